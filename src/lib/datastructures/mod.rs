@@ -1,2 +1,5 @@
 mod growable_ring_buffer;
-pub use growable_ring_buffer::*;
\ No newline at end of file
+pub use growable_ring_buffer::*;
+
+mod tree;
+pub use tree::*;
\ No newline at end of file
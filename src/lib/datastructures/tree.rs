@@ -0,0 +1,348 @@
+use std::{fmt::Debug, hash::Hash, iter, ops::Deref};
+
+use ahash::HashMap;
+use indexmap::IndexMap;
+use itertools::Itertools;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum TreeError {
+    #[error("Node {0} is listed more than once")]
+    DuplicateNode(String),
+    #[error("Node {0} is a child but is never listed")]
+    UnknownNode(String),
+    #[error("Node {0} has more than one parent")]
+    MultipleParents(String),
+    #[error("Some of the nodes form a cycle")]
+    Cycle,
+    #[error("A tree must have exactly one root, found {0}")]
+    RootCount(usize)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node<V> {
+    value: V,
+    parent: Option<usize>,
+    children: Vec<usize>
+}
+
+/// A collection of trees, where every node is identified by a unique key and holds a value.
+/// Nodes keep the order in which they were listed, so do the children of a node.
+/// ```
+/// # use aoc_lib::datastructures::Forest;
+/// let forest = Forest::from_adjacency([
+///     ("a", 1, vec!["b", "c"]),
+///     ("b", 2, vec![]),
+///     ("c", 3, vec!["d"]),
+///     ("d", 4, vec![]),
+///     ("e", 5, vec![]),
+/// ]).unwrap();
+///
+/// assert_eq!(vec![&"a", &"e"], forest.roots().collect::<Vec<_>>());
+/// assert_eq!(vec![&"b", &"d", &"c", &"a", &"e"], forest.post_order().map(|(key, _)| key).collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Forest<K, V> {
+    nodes: IndexMap<K, Node<V>>,
+    roots: Vec<usize>
+}
+
+impl<K: Hash + Eq + Debug, V> Forest<K, V> {
+    /// Builds the forest from the children of every node.
+    /// Every node has to be listed exactly once, including the leaves.
+    ///
+    /// # Errors
+    /// If a node is listed more than once, a child is never listed,
+    /// a node has multiple parents, or the nodes contain a cycle.
+    pub fn from_adjacency<C>(entries: impl IntoIterator<Item=(K, V, C)>) -> Result<Self, TreeError> where
+        C: IntoIterator<Item=K>
+    {
+        let mut nodes = IndexMap::new();
+        let mut adjacency = Vec::new();
+        for (key, value, children) in entries {
+            if nodes.contains_key(&key) {
+                return Err(TreeError::DuplicateNode(format!("{key:?}")));
+            }
+
+            nodes.insert(key, Node { value, parent: None, children: Vec::new() });
+            adjacency.push(children);
+        }
+
+        for (parent, children) in adjacency.into_iter().enumerate() {
+            for child in children {
+                let index = nodes
+                    .get_index_of(&child)
+                    .ok_or_else(|| TreeError::UnknownNode(format!("{child:?}")))?;
+
+                let node = &mut nodes[index];
+                if node.parent.replace(parent).is_some() {
+                    return Err(TreeError::MultipleParents(format!("{child:?}")));
+                }
+
+                nodes[parent].children.push(index);
+            }
+        }
+
+        let roots = nodes
+            .values()
+            .enumerate()
+            .filter(|(_, node)| node.parent.is_none())
+            .map(|(index, _)| index)
+            .collect();
+
+        let forest = Self { nodes, roots };
+
+        // Every node has at most one parent, so the nodes that can't be reached from a root are part of a cycle
+        if forest.pre_order().count() != forest.len() { return Err(TreeError::Cycle) }
+
+        Ok(forest)
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.nodes.get(key).map(|node| &node.value)
+    }
+
+    #[must_use]
+    pub fn contains(&self, key: &K) -> bool {
+        self.nodes.contains_key(key)
+    }
+
+    #[must_use]
+    pub fn parent(&self, key: &K) -> Option<&K> {
+        let parent = self.nodes.get(key)?.parent?;
+        self.nodes.get_index(parent).map(|(key, _)| key)
+    }
+
+    /// The children of the node, this is empty if the node does not exist.
+    pub fn children(&self, key: &K) -> impl Iterator<Item=&K> + use<'_, K, V> {
+        self.nodes
+            .get(key)
+            .into_iter()
+            .flat_map(|node| &node.children)
+            .map(|&child| self.key(child))
+    }
+
+    /// Finds the child whose measure differs from that of all of its siblings,
+    /// together with the measure shared by the siblings.
+    ///
+    /// Returns `None` if the children don't all agree except for a single one.
+    /// This includes having only two children that differ, as the odd one out is ambiguous.
+    pub fn odd_one_out<A: Eq>(&self, parent: &K, mut measure: impl FnMut(&K, &V) -> A) -> Option<(&K, A)> {
+        let mut measures: Vec<(&K, A)> = self.nodes
+            .get(parent)?
+            .children
+            .iter()
+            .map(|&child| {
+                let (key, node) = self.entry(child);
+                (key, measure(key, &node.value))
+            }).collect();
+
+        // Out of the first three children at least two share the common measure
+        let common = match &measures[..] {
+            [(_, first), (_, second), (_, third), ..] => if first == second || first == third { first } else { second },
+            _ => return None
+        };
+
+        let mut odd = measures.iter().positions(|(_, value)| value != common);
+        let index = odd.next()?;
+        if odd.next().is_some() { return None }
+
+        let (key, _) = measures.remove(index);
+        let (_, common) = measures.swap_remove(0);
+        Some((key, common))
+    }
+
+    /// Combines the values of every subtree bottom-up,
+    /// `combine` receives the value of a node and the aggregates of its children.
+    /// ```
+    /// # use aoc_lib::datastructures::Forest;
+    /// let forest = Forest::from_adjacency([
+    ///     ("root", 1, vec!["left", "right"]),
+    ///     ("left", 2, vec![]),
+    ///     ("right", 3, vec!["leaf"]),
+    ///     ("leaf", 4, vec![]),
+    /// ]).unwrap();
+    ///
+    /// let sums = forest.aggregate(|value, children: &[&i32]| value + children.iter().copied().sum::<i32>());
+    /// assert_eq!(10, sums[&"root"]);
+    /// assert_eq!(7, sums[&"right"]);
+    /// ```
+    pub fn aggregate<A>(&self, mut combine: impl FnMut(&V, &[&A]) -> A) -> HashMap<&K, A> {
+        let mut aggregates: Vec<Option<A>> = iter::repeat_with(|| None).take(self.len()).collect();
+        for index in self.post_order_indices() {
+            let node = &self.nodes[index];
+            let children: Vec<&A> = node.children
+                .iter()
+                .filter_map(|&child| aggregates[child].as_ref())
+                .collect();
+
+            aggregates[index] = Some(combine(&node.value, &children));
+        }
+
+        aggregates
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, aggregate)| Some((self.key(index), aggregate?)))
+            .collect()
+    }
+}
+
+impl<K, V> Forest<K, V> {
+    #[must_use]
+    pub fn len(&self) -> usize { self.nodes.len() }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.nodes.is_empty() }
+
+    /// The keys of the nodes without a parent.
+    pub fn roots(&self) -> impl Iterator<Item=&K> {
+        self.roots.iter().map(|&root| self.key(root))
+    }
+
+    /// Visits every node before its children, one tree at a time.
+    pub fn pre_order(&self) -> impl Iterator<Item=(&K, &V)> {
+        let mut stack: Vec<usize> = self.roots.iter().rev().copied().collect();
+        iter::from_fn(move || {
+            let index = stack.pop()?;
+            let (key, node) = self.entry(index);
+            stack.extend(node.children.iter().rev());
+            Some((key, &node.value))
+        })
+    }
+
+    /// Visits every node after its children, one tree at a time.
+    pub fn post_order(&self) -> impl Iterator<Item=(&K, &V)> {
+        self.post_order_indices()
+            .into_iter()
+            .map(|index| {
+                let (key, node) = self.entry(index);
+                (key, &node.value)
+            })
+    }
+
+    /// The nodes in the order in which they were listed.
+    pub fn iter(&self) -> impl Iterator<Item=(&K, &V)> {
+        self.nodes.iter().map(|(key, node)| (key, &node.value))
+    }
+
+    fn key(&self, index: usize) -> &K {
+        self.entry(index).0
+    }
+
+    fn entry(&self, index: usize) -> (&K, &Node<V>) {
+        self.nodes.get_index(index).expect("Node indices are always valid")
+    }
+
+    fn post_order_indices(&self) -> Vec<usize> {
+        // Visiting the children from right to left and reversing the result visits them from left to right
+        let mut stack = self.roots.clone();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(index) = stack.pop() {
+            order.push(index);
+            stack.extend(&self.nodes[index].children);
+        }
+
+        order.reverse();
+        order
+    }
+}
+
+/// A [`Forest`] consisting of a single tree.
+#[derive(Debug, Clone)]
+pub struct Tree<K, V> {
+    forest: Forest<K, V>
+}
+
+impl<K: Hash + Eq + Debug, V> Tree<K, V> {
+    /// Builds the tree from the children of every node.
+    ///
+    /// # Errors
+    /// If the nodes don't form a [`Forest`], or the forest does not have exactly one root.
+    pub fn from_adjacency<C>(entries: impl IntoIterator<Item=(K, V, C)>) -> Result<Self, TreeError> where
+        C: IntoIterator<Item=K>
+    {
+        Forest::from_adjacency(entries)?.try_into()
+    }
+}
+
+impl<K, V> Tree<K, V> {
+    #[must_use]
+    pub fn root(&self) -> &K {
+        self.forest.key(self.forest.roots[0])
+    }
+
+    #[must_use]
+    pub fn into_forest(self) -> Forest<K, V> { self.forest }
+}
+
+impl<K, V> TryFrom<Forest<K, V>> for Tree<K, V> {
+    type Error = TreeError;
+
+    fn try_from(forest: Forest<K, V>) -> Result<Self, Self::Error> {
+        match forest.roots.len() {
+            1 => Ok(Self { forest }),
+            roots => Err(TreeError::RootCount(roots))
+        }
+    }
+}
+
+impl<K, V> Deref for Tree<K, V> {
+    type Target = Forest<K, V>;
+
+    fn deref(&self) -> &Self::Target { &self.forest }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::datastructures::{Forest, Tree, TreeError};
+
+    #[test]
+    fn invalid_adjacency() {
+        let duplicate = Forest::from_adjacency([(1, (), vec![]), (1, (), vec![])]);
+        assert!(matches!(duplicate, Err(TreeError::DuplicateNode(_))));
+
+        let unknown = Forest::from_adjacency([(1, (), vec![2])]);
+        assert!(matches!(unknown, Err(TreeError::UnknownNode(_))));
+
+        let shared = Forest::from_adjacency([(1, (), vec![3]), (2, (), vec![3]), (3, (), vec![])]);
+        assert!(matches!(shared, Err(TreeError::MultipleParents(_))));
+
+        let cycle = Forest::from_adjacency([(1, (), vec![]), (2, (), vec![3]), (3, (), vec![2])]);
+        assert!(matches!(cycle, Err(TreeError::Cycle)));
+
+        let forest = Tree::from_adjacency([(1, (), vec![]), (2, (), vec![])]);
+        assert!(matches!(forest, Err(TreeError::RootCount(2))));
+    }
+
+    #[test]
+    fn traversal() {
+        let tree = Tree::from_adjacency([
+            ('b', (), vec!['d', 'e']),
+            ('a', (), vec!['b', 'c']),
+            ('c', (), vec![]),
+            ('d', (), vec![]),
+            ('e', (), vec![]),
+        ]).unwrap();
+
+        assert_eq!(&'a', tree.root());
+        assert_eq!(Some(&'b'), tree.parent(&'e'));
+        assert_eq!("abdec", tree.pre_order().map(|(key, ())| key).collect::<String>());
+        assert_eq!("debca", tree.post_order().map(|(key, ())| key).collect::<String>());
+    }
+
+    #[test]
+    fn odd_one_out() {
+        let forest = Forest::from_adjacency([
+            ("root", 0, vec!["a", "b", "c", "d"]),
+            ("pair", 0, vec!["e", "f"]),
+            ("a", 5, vec![]), ("b", 3, vec![]), ("c", 3, vec![]), ("d", 3, vec![]),
+            ("e", 1, vec![]), ("f", 2, vec![]),
+        ]).unwrap();
+
+        assert_eq!(Some((&"a", 3)), forest.odd_one_out(&"root", |_, &weight| weight));
+        assert_eq!(None, forest.odd_one_out(&"root", |_, _| ()));
+        assert_eq!(None, forest.odd_one_out(&"pair", |_, &weight| weight));
+        assert_eq!(None, forest.odd_one_out(&"a", |_, &weight| weight));
+    }
+}
\ No newline at end of file
@@ -1,9 +1,8 @@
-use std::{cmp::minmax, iter::Step, fmt::{Display, Formatter, self}, ops::{Sub, Add}, hash::Hash};
+use std::{cmp::{max, min, minmax}, iter::{once, Step}, fmt::{Display, Formatter, self}, ops::{Sub, Add, Mul}};
 
-use ahash::HashSet;
 use num::{Zero, One};
 
-use super::{Point2D, Dimensions};
+use super::{Point2D, Dimensions, Transform2D};
 
 #[derive(Debug, Eq, PartialEq, Hash, Clone)]
 pub struct Area<T> {
@@ -33,11 +32,28 @@ impl<T> Area<T> {
         }
     }
 
+    /// The amount of columns in the area.
+    pub fn width(self) -> T
+        where T: Sub<Output=T> + Add<Output=T> + One
+    {
+        self.bottom_right.x() - self.top_left.x() + T::one()
+    }
+
+    /// The amount of rows in the area.
+    pub fn height(self) -> T
+        where T: Sub<Output=T> + Add<Output=T> + One
+    {
+        self.bottom_right.y() - self.top_left.y() + T::one()
+    }
+
+    /// # Panics
+    /// If the width or height of the area does not fit in a `usize`.
     pub fn dimensions(self) -> Dimensions
-        where T: Into<usize> + Sub<Output=T> + Add<Output = T> + One
+        where T: TryInto<usize> + Sub<Output=T> + Add<Output=T> + One + Clone
     {
-        let Point2D(width, height) = self.bottom_right - self.top_left + Point2D::one();
-        Dimensions(width.into(), height.into())
+        let width = self.clone().width().try_into().ok().expect("Width of area should fit in a usize");
+        let height = self.height().try_into().ok().expect("Height of area should fit in a usize");
+        Dimensions(width, height)
     }
 
     pub fn top(self) -> T { self.top_left.y() }
@@ -48,20 +64,16 @@ impl<T> Area<T> {
     pub fn top_left(self) -> Point2D<T> { self.top_left }
     pub fn bottom_right(self) -> Point2D<T> { self.bottom_right }
 
-    pub fn top_right(self) -> Point2D<T>
-        where T: Zero
-    {
-        self.top_left + Point2D(self.bottom_right.x(), Zero::zero())
+    pub fn top_right(self) -> Point2D<T> {
+        Point2D(self.bottom_right.x(), self.top_left.y())
     }
 
-    pub fn bottom_left(self) -> Point2D<T>
-        where T: Add<Output=T> + Zero
-    {
-        self.top_left + Point2D(Zero::zero(), self.bottom_right.y())
+    pub fn bottom_left(self) -> Point2D<T> {
+        Point2D(self.top_left.x(), self.bottom_right.y())
     }
 
     pub fn corners(self) -> [Point2D<T>; 4]
-        where T: Zero + Add<Output = T> + Clone
+        where T: Clone
     {
         let top_left = self.top_left.clone();
         let bottom_right = self.bottom_right.clone();
@@ -71,18 +83,128 @@ impl<T> Area<T> {
         [top_left, top_right, bottom_left, bottom_right]
     }
 
-    /// Produces an iterator containing all the [`Point2D`]s on the edges of the area
+    /// Produces an iterator containing all the [`Point2D`]s on the edges of the area in reading order.
+    /// Every point is produced exactly once.
+    /// ```
+    /// # use aoc_lib::geometry::{Area, Point2D};
+    /// let area = Area::from_corners(Point2D(-1, 0), Point2D(1, 2));
+    /// assert_eq!(8, area.edges().count());
+    /// assert_eq!(1, Area::from_corners(Point2D(3, 3), Point2D(3, 3)).edges().count());
+    /// ```
     pub fn edges(self) -> impl Iterator<Item=Point2D<T>>
-        where T: Step + Copy,
-              Point2D<T>: Hash + Eq
+        where T: Step + Copy
+    {
+        let Self { top_left: Point2D(left, top), bottom_right: Point2D(right, bottom) } = self;
+        let row = move |y| (left..=right).map(move |x| Point2D(x, y));
+        let sides = move |y| once(Point2D(left, y)).chain((left != right).then_some(Point2D(right, y)));
+
+        row(top)
+            .chain((top..=bottom).filter(move |&y| y != top && y != bottom).flat_map(sides))
+            .chain((top != bottom).then(|| row(bottom)).into_iter().flatten())
+    }
+
+    /// The area containing the points that lie in both areas.
+    /// Returns `None` if the areas don't overlap.
+    /// ```
+    /// # use aoc_lib::geometry::{Area, Point2D};
+    /// let area = Area::from_corners(Point2D(-2, -2), Point2D(2, 2));
+    /// let other = Area::from_corners(Point2D(1, 0), Point2D(5, 5));
+    /// assert_eq!(Some(Area::from_corners(Point2D(1, 0), Point2D(2, 2))), area.intersect(&other));
+    /// assert_eq!(None, area.intersect(&other.translate(Point2D(2, 0))));
+    /// ```
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Option<Self>
+        where T: Ord + Clone
+    {
+        let left = max(self.left_ref(), other.left_ref()).clone();
+        let top = max(self.top_ref(), other.top_ref()).clone();
+        let right = min(self.right_ref(), other.right_ref()).clone();
+        let bottom = min(self.bottom_ref(), other.bottom_ref()).clone();
+
+        (left <= right && top <= bottom).then(|| Self {
+            top_left: Point2D(left, top),
+            bottom_right: Point2D(right, bottom)
+        })
+    }
+
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool
+        where T: Ord
+    {
+        self.left_ref() <= other.right_ref() && other.left_ref() <= self.right_ref() &&
+        self.top_ref() <= other.bottom_ref() && other.top_ref() <= self.bottom_ref()
+    }
+
+    /// The smallest area containing both areas.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self
+        where T: Ord + Clone
+    {
+        Self {
+            top_left: Point2D(
+                min(self.left_ref(), other.left_ref()).clone(),
+                min(self.top_ref(), other.top_ref()).clone()
+            ),
+            bottom_right: Point2D(
+                max(self.right_ref(), other.right_ref()).clone(),
+                max(self.bottom_ref(), other.bottom_ref()).clone()
+            )
+        }
+    }
+
+    /// Grows the area by the given amount in every direction.
+    /// ```
+    /// # use aoc_lib::geometry::{Area, Point2D};
+    /// let area = Area::from_corners(Point2D(0, 0), Point2D(2, 1)).expand(1);
+    /// assert_eq!(Area::from_corners(Point2D(-1, -1), Point2D(3, 2)), area);
+    /// assert_eq!((5, 4), (area.width(), area.height()));
+    /// ```
+    #[must_use]
+    pub fn expand(self, amount: T) -> Self
+        where T: Clone + Add<Output=T> + Sub<Output=T>
+    {
+        let offset = Point2D(amount.clone(), amount);
+        Self {
+            top_left: self.top_left - offset.clone(),
+            bottom_right: self.bottom_right + offset
+        }
+    }
+
+    /// Moves the area by the given offset.
+    #[must_use]
+    pub fn translate(self, offset: Point2D<T>) -> Self
+        where T: Clone + Add<Output=T>
+    {
+        Self {
+            top_left: self.top_left + offset.clone(),
+            bottom_right: self.bottom_right + offset
+        }
+    }
+
+    /// The point in the area that is closest to the given point.
+    #[must_use]
+    pub fn clamp(&self, Point2D(x, y): Point2D<T>) -> Point2D<T>
+        where T: Ord + Clone
+    {
+        Point2D(
+            x.clamp(self.left_ref().clone(), self.right_ref().clone()),
+            y.clamp(self.top_ref().clone(), self.bottom_ref().clone())
+        )
+    }
+
+    const fn left_ref(&self) -> &T { &self.top_left.0 }
+    const fn top_ref(&self) -> &T { &self.top_left.1 }
+    const fn right_ref(&self) -> &T { &self.bottom_right.0 }
+    const fn bottom_ref(&self) -> &T { &self.bottom_right.1 }
+
+    /// Applies a transformation to the area.
+    /// Transformations only consist of quarter turns, reflections and translations,
+    /// so the result is still aligned with the axes.
+    #[must_use]
+    pub fn transform(self, transform: &Transform2D<T>) -> Self
+        where T: Ord + Clone + Zero + Mul<Output=T>
     {
-        (self.left()..=self.right()).map(move |x| {
-            [Point2D(x, self.top()), Point2D(x, self.bottom())]
-        }).chain((self.top()..=self.bottom()).map(move |y| {
-            [Point2D(self.left(), y), Point2D(self.right(), y)]
-        })).flatten()
-            .collect::<HashSet<_>>()
-            .into_iter()
+        Self::from_corners(transform.apply(self.top_left), transform.apply(self.bottom_right))
     }
 
     pub fn contains(&self, Point2D(x, y): &Point2D<T>) -> bool
@@ -0,0 +1,213 @@
+use std::{array, ops::{Add, Mul, Sub}};
+
+use num::{One, Zero};
+
+use crate::math::Range;
+
+use super::{grid::Grid, Area, Cuboid, Dimensions, Point2D, PointN};
+
+/// Maps the distinct values along a single axis onto consecutive cells.
+///
+/// When gaps are preserved, the values in between two neighbouring values form a cell of their own.
+/// This keeps regions that are separated in the original space separated after compression.
+/// ```
+/// # use aoc_lib::{geometry::CompressedAxis, range};
+/// let axis = CompressedAxis::new([100, 3, 40, 41], true);
+/// assert_eq!(6, axis.len());
+/// assert_eq!(Some(5), axis.compress(&100));
+/// assert_eq!(Some(1), axis.compress(&20));
+/// assert_eq!(Some(range!(4..40)), axis.decompress(1));
+/// assert_eq!(Some(58), axis.weight(4));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompressedAxis<T> {
+    cells: Vec<Range<T>>
+}
+
+impl<T: Copy + Ord + One + Add<Output=T>> CompressedAxis<T> {
+    pub fn new(values: impl IntoIterator<Item=T>, preserve_gaps: bool) -> Self {
+        let mut values: Vec<T> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+
+        let mut cells: Vec<Range<T>> = Vec::with_capacity(if preserve_gaps { values.len() * 2 } else { values.len() });
+        for value in values {
+            let previous_end = cells.last().map(|cell| cell.end);
+            if preserve_gaps && let Some(end) = previous_end && end < value {
+                cells.push(Range::new_unchecked(end, value));
+            }
+
+            cells.push(Range::new_unchecked(value, value + T::one()));
+        }
+
+        Self { cells }
+    }
+
+    /// The amount of cells along the axis.
+    #[must_use]
+    pub const fn len(&self) -> usize { self.cells.len() }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool { self.cells.is_empty() }
+
+    /// The index of the cell containing the value.
+    /// Returns `None` if no cell contains the value.
+    #[must_use]
+    pub fn compress(&self, value: &T) -> Option<usize> {
+        let index = self.cells.partition_point(|cell| &cell.end <= value);
+        self.cells.get(index)
+            .filter(|cell| cell.contains(value))
+            .map(|_| index)
+    }
+
+    /// The original values that are represented by the cell.
+    #[must_use]
+    pub fn decompress(&self, index: usize) -> Option<Range<T>> {
+        self.cells.get(index).copied()
+    }
+
+    /// The amount of original values that are represented by the cell.
+    #[must_use]
+    pub fn weight(&self, index: usize) -> Option<T>
+        where T: Sub<Output=T>
+    {
+        self.decompress(index).map(Range::interval)
+    }
+}
+
+/// Compresses points in `D` dimensions by compressing each of the axes separately.
+///
+/// This makes a sparse set of points with huge coordinates small enough to work with densely,
+/// where every compressed cell remembers the region of the original space it represents.
+///
+/// Points can be given as a [`Point2D`], [`Point3D`](super::Point3D) or [`PointN`].
+/// ```
+/// # use aoc_lib::geometry::{CompressionSpace, Point2D, PointN};
+/// let corners = [Point2D(2, 1), Point2D(1_000, 1), Point2D(1_000, 50)];
+/// let space: CompressionSpace<i32> = CompressionSpace::new(corners, true);
+///
+/// assert_eq!(Some(PointN([2, 2])), space.compress(Point2D(1_000, 50)));
+/// assert_eq!(Some(Point2D(1, 1)), space.compress(Point2D(500, 20)).map(Point2D::from));
+/// assert_eq!(Some(997 * 48), space.weight(PointN([1, 1])));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompressionSpace<T, const D: usize = 2> {
+    axes: [CompressedAxis<T>; D]
+}
+
+impl<T: Copy + Ord + One + Add<Output=T>, const D: usize> CompressionSpace<T, D> {
+    pub fn new<P: Into<PointN<T, D>>>(points: impl IntoIterator<Item=P>, preserve_gaps: bool) -> Self {
+        let mut values: [Vec<T>; D] = array::from_fn(|_| Vec::new());
+        for point in points {
+            let PointN(components) = point.into();
+            for (axis, component) in components.into_iter().enumerate() {
+                values[axis].push(component);
+            }
+        }
+
+        Self { axes: values.map(|values| CompressedAxis::new(values, preserve_gaps)) }
+    }
+
+    #[must_use]
+    pub const fn axes(&self) -> &[CompressedAxis<T>; D] { &self.axes }
+
+    /// The location of the cell containing the point.
+    /// Returns `None` if the point lies outside of every cell.
+    #[must_use]
+    pub fn compress(&self, point: impl Into<PointN<T, D>>) -> Option<PointN<usize, D>> {
+        let PointN(components) = point.into();
+        let mut cell = [0; D];
+
+        for (axis, component) in components.iter().enumerate() {
+            cell[axis] = self.axes[axis].compress(component)?;
+        }
+
+        Some(PointN(cell))
+    }
+
+    /// The region of the original space that is represented by the cell.
+    #[must_use]
+    pub fn decompress(&self, cell: impl Into<PointN<usize, D>>) -> Option<Cuboid<T, D>> {
+        let PointN(indices) = cell.into();
+        let ranges: Vec<Range<T>> = indices
+            .into_iter()
+            .zip(&self.axes)
+            .map(|(index, axis)| axis.decompress(index))
+            .collect::<Option<_>>()?;
+
+        Some(Cuboid::new(ranges.try_into().ok()?))
+    }
+
+    /// The amount of points in the original space that are represented by the cell.
+    #[must_use]
+    pub fn weight(&self, cell: impl Into<PointN<usize, D>>) -> Option<T>
+        where T: Zero + Sub<Output=T> + Mul<Output=T>
+    {
+        self.decompress(cell).map(|region| region.volume())
+    }
+}
+
+impl<T: Copy + Ord + One + Add<Output=T> + Sub<Output=T>> CompressionSpace<T, 2> {
+    /// Lays out the compressed cells in a grid,
+    /// where every cell holds the area of the original space it represents.
+    #[must_use]
+    pub fn grid(&self) -> Grid<Area<T>> {
+        let [columns, rows] = &self.axes;
+        let tiles = rows.cells
+            .iter()
+            .flat_map(|row| columns.cells.iter().map(move |column| Area::from_corners(
+                Point2D(column.start, row.start),
+                Point2D(column.end - T::one(), row.end - T::one())
+            )))
+            .collect();
+
+        Grid { dimensions: Dimensions(columns.len(), rows.len()), tiles }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::{Area, CompressedAxis, CompressionSpace, Point2D, Point3D, PointN, grid::GridLike}, range};
+
+    #[test]
+    fn axis_without_gaps() {
+        let axis = CompressedAxis::new([7, 3, 3, 12], false);
+        assert_eq!(3, axis.len());
+        assert_eq!(Some(1), axis.compress(&7));
+        assert_eq!(None, axis.compress(&8));
+        assert_eq!(Some(range!(12..13)), axis.decompress(2));
+    }
+
+    #[test]
+    fn adjacent_values_have_no_gap() {
+        let axis = CompressedAxis::new([5, 6, 9], true);
+        assert_eq!(4, axis.len());
+        assert_eq!(Some(range!(7..9)), axis.decompress(2));
+        assert_eq!(None, axis.compress(&4));
+        assert_eq!(None, axis.compress(&10));
+    }
+
+    #[test]
+    fn weights_cover_the_original_space() {
+        let points = [Point3D(1, 10, -4), Point3D(6, 2, 0), Point3D(3, 3, 3)];
+        let space: CompressionSpace<i32, 3> = CompressionSpace::new(points, true);
+        let [x, y, z] = space.axes().each_ref().map(CompressedAxis::len);
+
+        let total: i32 = (0..x)
+            .flat_map(|i| (0..y).flat_map(move |j| (0..z).map(move |k| PointN([i, j, k]))))
+            .map(|cell| space.weight(cell).unwrap())
+            .sum();
+
+        assert_eq!(6 * 9 * 8, total);
+        assert_eq!(Some(PointN([0, 1, 0])), space.compress(Point3D(1, 3, -4)));
+    }
+
+    #[test]
+    fn grid() {
+        let space: CompressionSpace<i64> = CompressionSpace::new([Point2D(0, 0), Point2D(10, 4)], true);
+        let grid = space.grid();
+
+        assert_eq!(Some(&Area::from_corners(Point2D(1, 0), Point2D(9, 0))), grid.get(Point2D(1, 0)));
+        assert_eq!(Some(&Area::from_corners(Point2D(10, 1), Point2D(10, 3))), grid.get(Point2D(2, 1)));
+    }
+}
\ No newline at end of file
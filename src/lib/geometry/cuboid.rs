@@ -0,0 +1,164 @@
+use std::{array, fmt::{self, Display, Formatter}, ops::{Add, Mul, Sub}};
+
+use itertools::Itertools;
+use num::{One, Zero};
+
+use crate::math::Range;
+
+use super::{Area, Point2D, PointN};
+
+/// An axis aligned box in `D` dimensions, spanning a [`Range`] along every axis.
+/// The upper bound of every range is exclusive, so a cuboid is empty if any of its ranges is.
+/// ```
+/// # use aoc_lib::{geometry::Cuboid, range};
+/// let cuboid = Cuboid::new([range!(0..4), range!(0..4), range!(0..4)]);
+/// let corner = Cuboid::new([range!(2..6), range!(2..6), range!(2..6)]);
+///
+/// assert_eq!(64, cuboid.volume());
+/// assert_eq!(Some(8), cuboid.intersection(&corner).map(|overlap| overlap.volume()));
+/// assert_eq!(56, cuboid.subtract(&corner).iter().map(Cuboid::volume).sum::<i32>());
+/// assert_eq!(120, Cuboid::union_volume([cuboid, corner]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cuboid<T, const D: usize> {
+    pub ranges: [Range<T>; D]
+}
+
+impl<T: Copy, const D: usize> Copy for Cuboid<T, D> {}
+
+impl<T, const D: usize> Cuboid<T, D> {
+    #[must_use]
+    pub const fn new(ranges: [Range<T>; D]) -> Self {
+        Self { ranges }
+    }
+
+    /// Creates the cuboid from its smallest corner and the corner just beyond its largest corner.
+    #[must_use]
+    pub fn from_corners(PointN(min): PointN<T, D>, PointN(max): PointN<T, D>) -> Self {
+        let mut max = max.into_iter();
+        Self { ranges: min.map(|start| Range::new_unchecked(start, max.next().unwrap())) }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+        where T: PartialOrd
+    {
+        self.ranges.iter().any(|range| range.start >= range.end)
+    }
+
+    #[must_use]
+    pub fn contains(&self, PointN(point): &PointN<T, D>) -> bool
+        where T: PartialOrd
+    {
+        self.ranges.iter().zip(point).all(|(range, value)| range.contains(value))
+    }
+
+    /// The amount of unit cubes in the cuboid.
+    #[must_use]
+    pub fn volume(&self) -> T
+        where T: Copy + Ord + Zero + One + Sub<Output=T> + Mul<Output=T>
+    {
+        if self.is_empty() { return T::zero() }
+
+        self.ranges
+            .iter()
+            .fold(T::one(), |volume, range| volume * range.interval())
+    }
+}
+
+impl<T: Copy + Ord, const D: usize> Cuboid<T, D> {
+    /// The cuboid containing the points that lie in both cuboids.
+    /// Returns `None` if the cuboids don't overlap.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Self {
+            ranges: array::from_fn(|axis| {
+                let (a, b) = (self.ranges[axis], other.ranges[axis]);
+                Range::new_unchecked(a.start.max(b.start), a.end.min(b.end))
+            })
+        };
+
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Cuts the cuboid in two along an axis, at the given value.
+    /// The first part lies below the value and the second part contains the value itself.
+    /// A part is `None` if it would be empty.
+    #[must_use]
+    pub fn split(self, axis: usize, at: T) -> (Option<Self>, Option<Self>) {
+        let range = self.ranges[axis];
+        let mut lower = self;
+        let mut upper = self;
+        lower.ranges[axis].end = at.clamp(range.start, range.end);
+        upper.ranges[axis].start = at.clamp(range.start, range.end);
+
+        (
+            (!lower.is_empty()).then_some(lower),
+            (!upper.is_empty()).then_some(upper)
+        )
+    }
+
+    /// The disjoint cuboids that together contain the points in this cuboid but not in the other.
+    /// At most two pieces are produced per axis.
+    #[must_use]
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        if !self.overlaps(other) { return if self.is_empty() { Vec::new() } else { vec![*self] } }
+
+        let mut pieces = Vec::new();
+        let mut remaining = *self;
+
+        for (axis, range) in other.ranges.iter().enumerate() {
+            let (below, rest) = remaining.split(axis, range.start);
+            let Some((inside, above)) = rest.map(|rest| rest.split(axis, range.end)) else { break };
+
+            pieces.extend(below);
+            pieces.extend(above);
+            let Some(inside) = inside else { break };
+            remaining = inside;
+        }
+
+        pieces
+    }
+
+    /// The volume of the space covered by at least one of the cuboids.
+    #[must_use]
+    pub fn union_volume(cuboids: impl IntoIterator<Item=Self>) -> T
+        where T: Zero + One + Sub<Output=T> + Mul<Output=T>
+    {
+        let mut disjoint: Vec<Self> = Vec::new();
+
+        for cuboid in cuboids {
+            disjoint = disjoint
+                .iter()
+                .flat_map(|piece| piece.subtract(&cuboid))
+                .collect();
+
+            if !cuboid.is_empty() { disjoint.push(cuboid); }
+        }
+
+        disjoint
+            .iter()
+            .fold(T::zero(), |volume, cuboid| volume + cuboid.volume())
+    }
+}
+
+impl<T: Copy + One + Add<Output=T>> From<Area<T>> for Cuboid<T, 2> {
+    fn from(area: Area<T>) -> Self {
+        let (Point2D(left, top), Point2D(right, bottom)) = (area.top_left(), area.bottom_right());
+        Self::new([
+            Range::new_unchecked(left, right + T::one()),
+            Range::new_unchecked(top, bottom + T::one())
+        ])
+    }
+}
+
+impl<T: Display, const D: usize> Display for Cuboid<T, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ranges.iter().join(" x "))
+    }
+}
\ No newline at end of file
@@ -8,7 +8,7 @@ use crate::parsing::{TextParserResult, usize, Parsable};
 
 use super::Point2D;
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 pub struct Dimensions(pub usize, pub usize);
 
 impl Dimensions {
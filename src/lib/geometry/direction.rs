@@ -231,6 +231,36 @@ impl Direction2D {
             Self::Ordinal(Ord::NorthWest)
         ]
     }
+
+    /// Returns the [`CardinalDirection`] if this direction is not diagonal.
+    #[must_use]
+    pub const fn cardinal(self) -> Option<CardinalDirection> {
+        match self {
+            Self::Cardinal(direction) => Some(direction),
+            Self::Ordinal(_) => None
+        }
+    }
+
+    /// Returns the [`OrdinalDirection`] if this direction is diagonal.
+    #[must_use]
+    pub const fn ordinal(self) -> Option<OrdinalDirection> {
+        match self {
+            Self::Ordinal(direction) => Some(direction),
+            Self::Cardinal(_) => None
+        }
+    }
+}
+
+impl From<CardinalDirection> for Direction2D {
+    fn from(direction: CardinalDirection) -> Self {
+        Self::Cardinal(direction)
+    }
+}
+
+impl From<OrdinalDirection> for Direction2D {
+    fn from(direction: OrdinalDirection) -> Self {
+        Self::Ordinal(direction)
+    }
 }
 
 /// Directions that move along the axis of 3D space.
@@ -267,7 +297,7 @@ impl Direction3D {
     }
 }
 
-/// Directions that move to neighbouring tiles on a 2D hex grid.
+/// Directions that move to neighbouring tiles on a 2D hex grid with flat-topped hexes.
 /// ```text
 ///   \ N  /
 /// NW +--+ NE
@@ -324,4 +354,62 @@ impl HexDirection {
             value(Self::South, one_of("Ss"))
         )).parse(input)
     }
+}
+
+/// Directions that move to neighbouring tiles on a 2D hex grid with pointy-topped hexes.
+/// ```text
+///    NW  /\  NE
+///      /    \
+///  W  |      |  E
+///      \    /
+///    SW  \/  SE
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointyHexDirection {
+    East,
+    West,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest
+}
+
+impl<T> Directional<Point3D<T>> for PointyHexDirection
+    where T: Zero + One + Neg<Output=T>
+{
+    /// Used to calculate the offset created by one step in a direction.
+    fn direction_vector(self) -> Point3D<T> {
+        match self {
+            Self::East => Point3D(T::one(), T::zero(), -T::one()),
+            Self::NorthEast => Point3D(T::one(), -T::one(), T::zero()),
+            Self::NorthWest => Point3D(T::zero(), -T::one(), T::one()),
+            Self::West => Point3D(-T::one(), T::zero(), T::one()),
+            Self::SouthWest => Point3D(-T::one(), T::one(), T::zero()),
+            Self::SouthEast => Point3D(T::zero(), T::one(), -T::one())
+        }
+    }
+}
+
+impl PointyHexDirection {
+    /// All directions in counter-clockwise order, starting from the east.
+    #[must_use]
+    pub const fn all() -> [Self; 6] {
+        [
+            Self::East, Self::NorthEast, Self::NorthWest,
+            Self::West, Self::SouthWest, Self::SouthEast
+        ]
+    }
+
+    /// Parse a [`PointyHexDirection`] from a string.
+    /// Valid representations are: E, W, NE, NW, SE, and SW.
+    pub fn parse(input: &str) -> TextParserResult<'_, Self> {
+        alt((
+            value(Self::NorthEast, tag("ne").or(tag("NE"))),
+            value(Self::NorthWest, tag("nw").or(tag("NW"))),
+            value(Self::SouthEast, tag("se").or(tag("SE"))),
+            value(Self::SouthWest, tag("sw").or(tag("SW"))),
+            value(Self::East, one_of("Ee")),
+            value(Self::West, one_of("Ww"))
+        )).parse(input)
+    }
 }
\ No newline at end of file
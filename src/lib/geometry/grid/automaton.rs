@@ -0,0 +1,209 @@
+use std::{hash::Hash, iter::once, mem};
+
+use ahash::HashSet;
+
+use crate::{geometry::{Directional, Point2D}, iteration::Cycle};
+
+use super::{Grid, GridLike, SparseGrid};
+
+/// A collection of cells that a [`Automaton`] can compute the next generation of.
+pub trait Generation: Clone {
+    type Cell;
+    type Location;
+
+    /// Computes the next generation by applying the rule to every cell and its neighbours.
+    /// The result is written into `next`, overwriting whatever it contained before.
+    fn next_generation<D, R>(&self, next: &mut Self, neighbourhood: &[D], rule: &R)
+        where D: Directional<Point2D<isize>> + Copy,
+              R: Fn(&Self::Cell, &[&Self::Cell]) -> Self::Cell;
+
+    fn set_cell(&mut self, location: Self::Location, cell: Self::Cell);
+}
+
+impl<T: Clone> Generation for Grid<T> {
+    type Cell = T;
+    type Location = Point2D<usize>;
+
+    /// Neighbours that lie outside of the grid are not passed to the rule.
+    fn next_generation<D, R>(&self, next: &mut Self, neighbourhood: &[D], rule: &R)
+        where D: Directional<Point2D<isize>> + Copy,
+              R: Fn(&T, &[&T]) -> T
+    {
+        assert_eq!(self.dimensions, next.dimensions, "Generations should have the same dimensions");
+
+        let mut neighbours = Vec::with_capacity(neighbourhood.len());
+        for ((location, cell), target) in self.enumerate().zip(next.tiles.iter_mut()) {
+            neighbours.clear();
+            neighbours.extend(self
+                .neighbours(location, neighbourhood.iter().copied())
+                .map(|(_, neighbour)| neighbour)
+            );
+
+            *target = rule(cell, &neighbours);
+        }
+    }
+
+    fn set_cell(&mut self, location: Point2D<usize>, cell: T) {
+        self[location] = cell;
+    }
+}
+
+impl<T: Clone + Default + PartialEq> Generation for SparseGrid<T> {
+    type Cell = T;
+    type Location = Point2D<isize>;
+
+    /// Cells that are missing from the grid are treated as the default value,
+    /// cells that become the default value are removed from the grid.
+    fn next_generation<D, R>(&self, next: &mut Self, neighbourhood: &[D], rule: &R)
+        where D: Directional<Point2D<isize>> + Copy,
+              R: Fn(&T, &[&T]) -> T
+    {
+        let empty = T::default();
+
+        // Only the occupied cells and the cells that have them as a neighbour can change
+        let candidates: HashSet<Point2D<isize>> = self.locations()
+            .flat_map(|&location| once(location).chain(neighbourhood
+                .iter()
+                .map(move |direction| location - direction.direction_vector())
+            ))
+            .collect();
+
+        next.clear();
+        let mut neighbours = Vec::with_capacity(neighbourhood.len());
+        for location in candidates {
+            neighbours.clear();
+            neighbours.extend(neighbourhood.iter().map(|direction| {
+                self.get(&(location + direction.direction_vector())).unwrap_or(&empty)
+            }));
+
+            let cell = rule(self.get(&location).unwrap_or(&empty), &neighbours);
+            if cell != empty { next.insert(location, cell); }
+        }
+    }
+
+    fn set_cell(&mut self, location: Point2D<isize>, cell: T) {
+        if cell == T::default() { self.remove(&location); }
+        else { self.insert(location, cell); }
+    }
+}
+
+/// A [cellular automaton](https://en.wikipedia.org/wiki/Cellular_automaton)
+/// where the next state of every cell is decided by a rule,
+/// based on the current state of the cell and that of its neighbours.
+///
+/// Pinned cells keep their value regardless of the rule.
+/// ```
+/// # use aoc_lib::{geometry::{Direction2D, Point2D, grid::{Automaton, Grid, GridLike}}, iteration::ExtraIter, math::Bit};
+/// let blinker: Grid<Bit> = Grid::parse("...\n###\n...").unwrap();
+/// let life = Automaton::new(Direction2D::all(), |&cell, neighbours: &[&Bit]| {
+///     match (cell, neighbours.iter().count_where(|bit| bit.is_on())) {
+///         (Bit::On, 2 | 3) | (Bit::Off, 3) => Bit::On,
+///         _ => Bit::Off
+///     }
+/// });
+///
+/// let cycle = life.find_cycle(blinker);
+/// assert_eq!((0, 2), (cycle.start, cycle.length));
+/// assert_eq!(Some(&Bit::On), cycle.nth(1).get(Point2D(1, 0)));
+/// ```
+pub struct Automaton<T, D, R, L = Point2D<usize>> {
+    neighbourhood: Vec<D>,
+    rule: R,
+    pinned: Vec<(L, T)>
+}
+
+impl<T, D, R, L> Automaton<T, D, R, L>
+    where T: Clone,
+          L: Clone,
+          D: Directional<Point2D<isize>> + Copy,
+          R: Fn(&T, &[&T]) -> T
+{
+    /// Creates an automaton where the neighbours of a cell are found by taking a step in every direction of the neighbourhood.
+    pub fn new(neighbourhood: impl IntoIterator<Item=D>, rule: R) -> Self {
+        Self {
+            neighbourhood: neighbourhood.into_iter().collect(),
+            rule,
+            pinned: Vec::new()
+        }
+    }
+
+    /// Makes a cell keep the given value in every generation, including the initial one.
+    #[must_use]
+    pub fn pin(mut self, location: L, cell: T) -> Self {
+        self.pinned.push((location, cell));
+        self
+    }
+
+    fn apply_pins<G>(&self, generation: &mut G)
+        where G: Generation<Cell=T, Location=L>
+    {
+        for (location, cell) in &self.pinned {
+            generation.set_cell(location.clone(), cell.clone());
+        }
+    }
+
+    fn step_into<G>(&self, current: &G, next: &mut G)
+        where G: Generation<Cell=T, Location=L>
+    {
+        current.next_generation(next, &self.neighbourhood, &self.rule);
+        self.apply_pins(next);
+    }
+
+    /// Computes the generation following the given one.
+    #[must_use]
+    pub fn step<G>(&self, generation: &G) -> G
+        where G: Generation<Cell=T, Location=L>
+    {
+        let mut next = generation.clone();
+        self.step_into(generation, &mut next);
+        next
+    }
+
+    /// Computes the state after the given amount of generations.
+    /// Only two generations are kept in memory, which are swapped every generation.
+    #[must_use]
+    pub fn run<G>(&self, mut generation: G, generations: usize) -> G
+        where G: Generation<Cell=T, Location=L>
+    {
+        self.apply_pins(&mut generation);
+        let mut next = generation.clone();
+
+        for _ in 0..generations {
+            self.step_into(&generation, &mut next);
+            mem::swap(&mut generation, &mut next);
+        }
+
+        generation
+    }
+
+    /// Runs the automaton until it reaches a generation it has been in before.
+    #[must_use]
+    pub fn find_cycle<G>(&self, mut generation: G) -> Cycle<G>
+        where G: Generation<Cell=T, Location=L> + Hash + Eq
+    {
+        self.apply_pins(&mut generation);
+        Cycle::find(generation, |generation| self.step(generation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::{Direction2D, Point2D, grid::{Automaton, SparseGrid}}, iteration::ExtraIter};
+
+    #[test]
+    fn sparse_cycle() {
+        let blinker: SparseGrid<bool> = [Point2D(-1, 0), Point2D(0, 0), Point2D(1, 0)]
+            .into_iter()
+            .map(|location| (location, true))
+            .collect();
+
+        let life: Automaton<bool, _, _, Point2D<isize>> = Automaton::new(Direction2D::all(), |&cell, neighbours: &[&bool]| {
+            matches!((cell, neighbours.iter().count_where(|&&&alive| alive)), (true, 2 | 3) | (false, 3))
+        });
+
+        let cycle = life.find_cycle(blinker.clone());
+        assert_eq!((0, 2), (cycle.start, cycle.length));
+        assert!(cycle.nth(1).contains(&Point2D(0, -1)));
+        assert_eq!(&blinker, cycle.nth(2));
+    }
+}
\ No newline at end of file
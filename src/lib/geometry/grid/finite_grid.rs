@@ -1,13 +1,13 @@
-use std::{fmt::{Debug, Display}, vec};
+use std::{fmt::{Debug, Display}, iter, vec};
 
 use itertools::Itertools;
 use thiserror::Error;
 
-use crate::geometry::{Point2D, Area, Dimensions, NotRectangularError};
+use crate::geometry::{Point2D, Area, Dimensions, NotRectangularError, RotationDirection};
 
 use super::{GridLike, GridView, GridViewMut, grid_like::{impl_grid_traits, impl_grid_traits_mut, GridLikeMut}};
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T>
 {
     pub(crate) dimensions: Dimensions,
@@ -173,6 +173,77 @@ impl<T> Grid<T>
 
         Grid { dimensions, tiles }
     }
+
+    /// Moves every tile to a new location in a grid with the given dimensions.
+    /// The mapping has to assign every tile a distinct location within those dimensions.
+    fn rearrange(self, dimensions: Dimensions, mapping: impl Fn(Point2D<usize>) -> Point2D<usize>) -> Self {
+        let width = self.dimensions.width();
+        let tiles = self.into_iter()
+            .enumerate()
+            .map(|(index, tile)| {
+                let Point2D(x, y) = mapping(Point2D(index % width, index / width));
+                (y * dimensions.width() + x, tile)
+            })
+            .sorted_unstable_by_key(|&(index, _)| index)
+            .map(|(_, tile)| tile)
+            .collect_vec()
+            .into_boxed_slice();
+
+        Self { dimensions, tiles }
+    }
+
+    /// Mirrors the grid along the diagonal from the top left to the bottom right,
+    /// turning its rows into columns.
+    #[must_use]
+    pub fn transpose(self) -> Self {
+        let Dimensions(width, height) = self.dimensions;
+        self.rearrange(Dimensions(height, width), |Point2D(x, y)| Point2D(y, x))
+    }
+
+    /// Rotates the grid by 90 degrees in the given direction.
+    /// ```
+    /// # use aoc_lib::geometry::{RotationDirection, grid::Grid};
+    /// let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let rotated = Grid::new(vec![vec![4, 1], vec![5, 2], vec![6, 3]]).unwrap();
+    /// assert_eq!(rotated, grid.clone().rotate(RotationDirection::Right));
+    /// assert_eq!(grid, rotated.rotate(RotationDirection::Left));
+    /// ```
+    #[must_use]
+    pub fn rotate(self, direction: RotationDirection) -> Self {
+        let Dimensions(width, height) = self.dimensions;
+        let dimensions = Dimensions(height, width);
+
+        match direction {
+            RotationDirection::Right => self.rearrange(dimensions, |Point2D(x, y)| Point2D(height - y - 1, x)),
+            RotationDirection::Left => self.rearrange(dimensions, |Point2D(x, y)| Point2D(y, width - x - 1))
+        }
+    }
+
+    /// Mirrors the grid from left to right.
+    #[must_use]
+    pub fn flip_horizontal(self) -> Self {
+        let dimensions = self.dimensions;
+        self.rearrange(dimensions, |Point2D(x, y)| Point2D(dimensions.width() - x - 1, y))
+    }
+
+    /// Mirrors the grid from top to bottom.
+    #[must_use]
+    pub fn flip_vertical(self) -> Self {
+        let dimensions = self.dimensions;
+        self.rearrange(dimensions, |Point2D(x, y)| Point2D(x, dimensions.height() - y - 1))
+    }
+
+    /// The 8 ways the grid can be rotated and mirrored, starting with the grid itself.
+    /// The first 4 are the clockwise rotations, followed by the rotations of the mirrored grid.
+    pub fn symmetries(&self) -> impl Iterator<Item=Self>
+        where T: Clone
+    {
+        let rotations = |grid: Self| iter::successors(Some(grid), |grid| {
+            Some(grid.clone().rotate(RotationDirection::Right))
+        }).take(4);
+
+        rotations(self.clone()).chain(rotations(self.clone().flip_horizontal()))
+    }
 }
 
 impl<T> IntoIterator for Grid<T> {
@@ -1,8 +1,8 @@
-use std::{slice, vec, fmt::Debug, iter::Enumerate};
+use std::{slice, vec, fmt::Debug, iter::{self, Enumerate}, mem};
 
 use thiserror::Error;
 
-use crate::geometry::{Point2D, Area, WrongDimensionsError};
+use crate::geometry::{Point2D, Area, Directional, WrongDimensionsError};
 
 use super::{Grid, InvalidGridAreaError};
 
@@ -37,6 +37,48 @@ pub trait GridLike: Sized {
     fn enumerate_columns(&self) -> Enumerate<GridColumnIterator<'_, Self>> {
         self.iter_columns().enumerate()
     }
+
+    /// The neighbours of a location when taking a step in every direction.
+    /// Neighbours that would lie outside the grid are not included.
+    fn neighbours<D>(&self, location: Point2D<usize>, directions: impl IntoIterator<Item=D>) -> impl Iterator<Item=(Point2D<usize>, &Self::GridItem)>
+        where D: Directional<Point2D<isize>>
+    {
+        location
+            .neighbours::<isize, D>(directions)
+            .filter_map(|neighbour| Some((neighbour, self.get(neighbour)?)))
+    }
+
+    /// Walks from a location in a straight line until the edge of the grid.
+    /// The starting location itself is not included.
+    /// ```
+    /// # use aoc_lib::geometry::{CardinalDirection, Point2D, grid::{Grid, GridLike}};
+    /// let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let ray: Vec<&u32> = grid.ray(Point2D(2, 1), CardinalDirection::West).map(|(_, tile)| tile).collect();
+    /// assert_eq!(vec![&5, &4], ray);
+    /// ```
+    fn ray<D>(&self, location: Point2D<usize>, direction: D) -> impl Iterator<Item=(Point2D<usize>, &Self::GridItem)>
+        where D: Directional<Point2D<isize>>
+    {
+        let step: Point2D<isize> = direction.direction_vector();
+        iter::successors(location.checked_add(step), move |&location| location.checked_add(step))
+            .map_while(|location| Some((location, self.get(location)?)))
+    }
+
+    /// Finds the first location, in reading order, of a tile that satisfies the predicate.
+    fn find(&self, mut predicate: impl FnMut(&Self::GridItem) -> bool) -> Option<Point2D<usize>> {
+        self.enumerate()
+            .find(|(_, tile)| predicate(tile))
+            .map(|(location, _)| location)
+    }
+
+    /// All locations, in reading order, that contain the given value.
+    fn positions<'a>(&'a self, value: &'a Self::GridItem) -> impl Iterator<Item=Point2D<usize>> + 'a
+        where Self::GridItem: PartialEq
+    {
+        self.enumerate()
+            .filter(move |&(_, tile)| tile == value)
+            .map(|(location, _)| location)
+    }
 }
 
 pub trait GridLikeMut: GridLike {
@@ -67,6 +109,40 @@ pub trait GridLikeMut: GridLike {
 
         Ok(())
     }
+
+    /// Cyclically shifts the tiles in a row, positive amounts move the tiles to the right.
+    fn shift_row(&mut self, row: usize, amount: isize) {
+        let row = self.get_row_mut(row)
+            .unwrap_or_else(|| panic!("Grid row index out of range: {row}"));
+
+        if row.is_empty() { return }
+        let amount = amount.rem_euclid(row.len().cast_signed()).cast_unsigned();
+        row.rotate_right(amount);
+    }
+
+    /// Cyclically shifts the tiles in a column, positive amounts move the tiles down.
+    fn shift_column(&mut self, column: usize, amount: isize) {
+        let mut column = self.get_column_mut(column)
+            .unwrap_or_else(|| panic!("Grid column index out of range: {column}"));
+
+        if column.is_empty() { return }
+        let amount = amount.rem_euclid(column.len().cast_signed()).cast_unsigned();
+
+        // Rotating the column itself would only reorder the references,
+        // instead the tiles are swapped into place by reversing the column twice.
+        reverse_tiles(&mut column);
+        let (front, back) = column.split_at_mut(amount);
+        reverse_tiles(front);
+        reverse_tiles(back);
+    }
+}
+
+fn reverse_tiles<T>(tiles: &mut [&mut T]) {
+    let length = tiles.len();
+    for index in 0..length / 2 {
+        let (front, back) = tiles.split_at_mut(length - index - 1);
+        mem::swap(front[index], back[0]);
+    }
 }
 
 pub struct GridColumnIterator<'a, G: GridLike> {
@@ -2,8 +2,20 @@ mod finite_grid;
 mod grid_view;
 mod grid_like;
 mod bit_grid;
+mod packed_bit_grid;
+mod sparse_grid;
+mod tiling_view;
+mod pathfinding;
+mod regions;
+mod automaton;
 
 pub use grid_like::{GridColumnIterator, GridRowIterator, GridLike, GridLikeMut, GridError};
 pub use finite_grid::*;
 pub use grid_view::*;
-pub use bit_grid::*;
\ No newline at end of file
+pub use bit_grid::*;
+pub use packed_bit_grid::*;
+pub use sparse_grid::*;
+pub use tiling_view::*;
+pub use pathfinding::*;
+pub use regions::*;
+pub use automaton::*;
\ No newline at end of file
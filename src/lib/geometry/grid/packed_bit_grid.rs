@@ -0,0 +1,182 @@
+use std::{fmt::{self, Display, Formatter}, ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not}};
+
+use bitvec::{order::BitOrder, slice::BitSlice, store::BitStore, vec::BitVec};
+use itertools::Itertools;
+
+use crate::{geometry::{Area, Dimensions, NotRectangularError, Point2D}, math::Bit, parsing::InvalidTokenError};
+
+use super::{Grid, GridLike, GridParseError};
+
+/// A grid of bits that packs its cells into machine words.
+/// The cells are stored row by row, which makes counting, combining, comparing and hashing entire grids cheap.
+/// ```
+/// # use aoc_lib::{geometry::{Point2D, grid::PackedBitGrid}, math::Bit};
+/// let glider = PackedBitGrid::parse(".#.\n..#\n###").unwrap();
+/// let top = PackedBitGrid::parse("###\n...\n...").unwrap();
+///
+/// assert_eq!(5, glider.pop_count());
+/// assert_eq!(Some(Bit::On), glider.get(Point2D(2, 1)));
+/// assert_eq!(7, (glider.clone() | &top).pop_count());
+/// assert_eq!(1, (glider & &top).pop_count());
+/// assert_eq!(6, (!top).pop_count());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PackedBitGrid {
+    dimensions: Dimensions,
+    bits: BitVec
+}
+
+impl PackedBitGrid {
+    #[must_use]
+    pub fn empty(dimensions: Dimensions) -> Self {
+        Self { dimensions, bits: BitVec::repeat(false, dimensions.surface_area()) }
+    }
+
+    pub fn from_rows<T, O>(rows: impl IntoIterator<Item=impl AsRef<BitSlice<T, O>>>) -> Result<Self, NotRectangularError>
+        where T: BitStore,
+              O: BitOrder
+    {
+        let mut bits = BitVec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for row in rows {
+            let row = row.as_ref();
+            if *width.get_or_insert(row.len()) != row.len() {
+                return Err(NotRectangularError)
+            }
+
+            bits.extend_from_bitslice(row);
+            height += 1;
+        }
+
+        Ok(Self { dimensions: Dimensions(width.unwrap_or_default(), height), bits })
+    }
+
+    /// Parses a grid where `#` (or `1`) is on and `.` (or `0`) is off.
+    pub fn parse(input: &str) -> Result<Self, GridParseError<InvalidTokenError<char>>> {
+        let rows: Vec<BitVec> = input.lines()
+            .map(|line| line
+                .chars()
+                .map(|char| Bit::try_from(char).map(bool::from))
+                .try_collect()
+            )
+            .try_collect()
+            .map_err(GridParseError::InvalidToken)?;
+
+        Ok(Self::from_rows(rows)?)
+    }
+
+    #[must_use]
+    pub const fn dimensions(&self) -> Dimensions { self.dimensions }
+
+    #[must_use]
+    pub fn area(&self) -> Area<usize> { self.dimensions.into() }
+
+    const fn backing_index(&self, Point2D(x, y): Point2D<usize>) -> Option<usize> {
+        if x >= self.dimensions.width() || y >= self.dimensions.height() { return None }
+        Some(y * self.dimensions.width() + x)
+    }
+
+    #[must_use]
+    pub fn get(&self, location: Point2D<usize>) -> Option<Bit> {
+        let index = self.backing_index(location)?;
+        Some(Bit::from(self.bits[index]))
+    }
+
+    pub fn set(&mut self, location: Point2D<usize>, bit: Bit) {
+        let index = self.backing_index(location)
+            .unwrap_or_else(|| panic!("Location {location} lies outside of the grid"));
+
+        self.bits.set(index, bit.into());
+    }
+
+    pub fn fill(&mut self, bit: Bit) {
+        self.bits.fill(bit.into());
+    }
+
+    #[must_use]
+    pub fn row(&self, row: usize) -> Option<&BitSlice> {
+        if row >= self.dimensions.height() { return None }
+        let start = row * self.dimensions.width();
+        Some(&self.bits[start..start + self.dimensions.width()])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=Bit> + '_ {
+        self.bits.iter().by_vals().map(Bit::from)
+    }
+
+    pub fn enumerate(&self) -> impl Iterator<Item=(Point2D<usize>, Bit)> + '_ {
+        self.area().into_iter().zip(self.iter())
+    }
+
+    /// Computes the "population count" of the grid
+    /// (The amount of cells that are on)
+    #[must_use]
+    pub fn pop_count(&self) -> usize {
+        self.bits.count_ones()
+    }
+}
+
+impl<G: GridLike<GridItem = Bit>> From<&G> for PackedBitGrid {
+    fn from(grid: &G) -> Self {
+        let bits = grid.iter().map(|&bit| bool::from(bit)).collect();
+        Self { dimensions: grid.area().dimensions(), bits }
+    }
+}
+
+impl From<&PackedBitGrid> for Grid<Bit> {
+    fn from(grid: &PackedBitGrid) -> Self {
+        Self {
+            dimensions: grid.dimensions,
+            tiles: grid.iter().collect()
+        }
+    }
+}
+
+macro_rules! impl_bit_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl $assign_trait<&Self> for PackedBitGrid {
+            fn $assign_method(&mut self, rhs: &Self) {
+                assert_eq!(self.dimensions, rhs.dimensions, "Can't combine grids with different dimensions");
+                self.bits.$assign_method(rhs.bits.as_bitslice());
+            }
+        }
+
+        impl $trait<&Self> for PackedBitGrid {
+            type Output = Self;
+
+            fn $method(mut self, rhs: &Self) -> Self::Output {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+    };
+}
+
+impl_bit_operator!(BitAnd, bitand, BitAndAssign, bitand_assign);
+impl_bit_operator!(BitOr, bitor, BitOrAssign, bitor_assign);
+impl_bit_operator!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl Not for PackedBitGrid {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self { dimensions: self.dimensions, bits: !self.bits }
+    }
+}
+
+impl Display for PackedBitGrid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let string = (0..self.dimensions.height())
+            .map(|row| self.row(row).unwrap()
+                .iter()
+                .by_vals()
+                .map(|bit| Bit::from(bit).to_string())
+                .join("")
+            )
+            .join("\n");
+
+        write!(f, "{string}")
+    }
+}
\ No newline at end of file
@@ -0,0 +1,279 @@
+use std::{cmp::Reverse, collections::VecDeque, hash::Hash, iter::once};
+
+use ahash::{HashMap, HashMapExt, HashSet, HashSetExt, RandomState};
+use itertools::Itertools;
+use num::Zero;
+use priority_queue::PriorityQueue;
+
+use crate::geometry::{Directional, Point2D};
+
+use super::GridLike;
+
+/// The result of a search for the shortest paths between the starting states and the goal states.
+/// Besides the cost it remembers every way the goals could have been reached at that cost.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S, C> {
+    /// The cost of the shortest paths.
+    pub cost: C,
+    /// The goals that were reached by a shortest path.
+    pub goals: Vec<S>,
+    predecessors: HashMap<S, Vec<S>>
+}
+
+impl<S: Clone + Eq + Hash, C> ShortestPaths<S, C> {
+    /// One of the shortest paths, from a start up to and including a goal.
+    #[must_use]
+    pub fn path(&self) -> Vec<S> {
+        let mut path = vec![self.goals[0].clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()).and_then(|states| states.first()) {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        path
+    }
+
+    /// Every shortest path, from a start up to and including a goal.
+    /// The amount of paths can grow exponentially with their length.
+    pub fn paths(&self) -> impl Iterator<Item=Vec<S>> + '_ {
+        let mut stack = self.goals.iter().map(|goal| vec![goal.clone()]).collect_vec();
+
+        std::iter::from_fn(move || {
+            while let Some(path) = stack.pop() {
+                match self.predecessors.get(path.last().unwrap()) {
+                    None => {
+                        let mut path = path;
+                        path.reverse();
+                        return Some(path);
+                    },
+                    Some(previous) => stack.extend(previous.iter().map(|state| {
+                        path.iter().cloned().chain(once(state.clone())).collect()
+                    }))
+                }
+            }
+
+            None
+        })
+    }
+
+    /// All states that lie on at least one of the shortest paths.
+    #[must_use]
+    pub fn states(&self) -> HashSet<S> {
+        let mut states = HashSet::new();
+        let mut stack = self.goals.clone();
+
+        while let Some(state) = stack.pop() {
+            if !states.insert(state.clone()) { continue }
+            if let Some(previous) = self.predecessors.get(&state) {
+                stack.extend(previous.iter().cloned());
+            }
+        }
+
+        states
+    }
+}
+
+/// Finds the shortest paths from any of the starting states to a goal, where every step costs one.
+///
+/// This uses [breadth-first search](https://en.wikipedia.org/wiki/Breadth-first_search).
+/// Returns `None` if no goal can be reached.
+/// ```
+/// # use std::iter::once;
+/// # use aoc_lib::geometry::grid::bfs;
+/// let paths = bfs(once(1), |&n: &u32| [n + 1, n * 2], |&n| n == 6).unwrap();
+/// assert_eq!(3, paths.cost);
+/// assert_eq!(2, paths.paths().count());
+/// ```
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item=S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool
+) -> Option<ShortestPaths<S, usize>>
+    where S: Clone + Eq + Hash,
+          I: IntoIterator<Item=S>
+{
+    let mut costs = HashMap::<S, usize>::new();
+    let mut predecessors = HashMap::<S, Vec<S>>::new();
+    let mut queue = VecDeque::new();
+    let mut best: Option<usize> = None;
+    let mut goals = Vec::new();
+
+    for start in starts {
+        costs.insert(start.clone(), 0);
+        queue.push_back(start);
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let cost = costs[&state];
+        if best.is_some_and(|best| cost > best) { break }
+
+        if is_goal(&state) {
+            best = Some(cost);
+            goals.push(state);
+            continue;
+        }
+
+        for next in successors(&state) {
+            match costs.get(&next) {
+                Some(&known) if known == cost + 1 => predecessors.entry(next).or_default().push(state.clone()),
+                Some(_) => {},
+                None => {
+                    costs.insert(next.clone(), cost + 1);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    Some(ShortestPaths { cost: best?, goals, predecessors })
+}
+
+/// Finds the shortest paths from any of the starting states to a goal using [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm).
+///
+/// The successors of a state are paired with the cost of moving to them.
+/// Returns `None` if no goal can be reached.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item=S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool
+) -> Option<ShortestPaths<S, C>>
+    where S: Clone + Eq + Hash,
+          C: Clone + Ord + Zero,
+          I: IntoIterator<Item=(S, C)>
+{
+    a_star(starts, successors, |_| C::zero(), is_goal)
+}
+
+/// Finds the shortest paths from any of the starting states to a goal using the [A* search algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm).
+///
+/// The heuristic estimates the remaining cost to a goal,
+/// it must never overestimate the cost for the result to be correct.
+/// Steps that cost nothing are allowed, they are never used to form a loop of shortest paths.
+/// Returns `None` if no goal can be reached.
+pub fn a_star<S, C, I>(
+    starts: impl IntoIterator<Item=S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool
+) -> Option<ShortestPaths<S, C>>
+    where S: Clone + Eq + Hash,
+          C: Clone + Ord + Zero,
+          I: IntoIterator<Item=(S, C)>
+{
+    let mut costs = HashMap::<S, C>::new();
+    let mut predecessors = HashMap::<S, Vec<S>>::new();
+    let mut queue = PriorityQueue::<S, Reverse<C>, RandomState>::with_default_hasher();
+    let mut best: Option<C> = None;
+    let mut goals = Vec::new();
+
+    for start in starts {
+        costs.insert(start.clone(), C::zero());
+        let estimate = heuristic(&start);
+        queue.push_increase(start, Reverse(estimate));
+    }
+
+    while let Some((state, Reverse(estimate))) = queue.pop() {
+        if best.as_ref().is_some_and(|best| &estimate > best) { break }
+        let cost = costs[&state].clone();
+
+        if is_goal(&state) {
+            best = Some(cost);
+            goals.push(state);
+            continue;
+        }
+
+        for (next, step) in successors(&state) {
+            let free = step.is_zero();
+            let next_cost = cost.clone() + step;
+            match costs.get(&next) {
+                Some(known) if known < &next_cost => continue,
+                Some(known) if known == &next_cost => {
+                    // A step that costs nothing would loop back if the next state precedes this one
+                    if !(free && precedes(&predecessors, &next, &state)) {
+                        predecessors.entry(next).or_default().push(state.clone());
+                    }
+
+                    continue;
+                },
+                _ => {}
+            }
+
+            let estimate = next_cost.clone() + heuristic(&next);
+            costs.insert(next.clone(), next_cost);
+            predecessors.insert(next.clone(), vec![state.clone()]);
+            queue.push_increase(next, Reverse(estimate));
+        }
+    }
+
+    Some(ShortestPaths { cost: best?, goals, predecessors })
+}
+
+/// Whether `state` can be reached from `successor` by following the predecessors.
+fn precedes<S: Eq + Hash>(predecessors: &HashMap<S, Vec<S>>, state: &S, successor: &S) -> bool {
+    let mut visited = HashSet::new();
+    let mut stack = vec![successor];
+
+    while let Some(current) = stack.pop() {
+        if current == state { return true }
+        if !visited.insert(current) { continue }
+        stack.extend(predecessors.get(current).into_iter().flatten());
+    }
+
+    false
+}
+
+pub trait GridPathfinding: GridLike {
+    /// Finds the shortest paths between two locations,
+    /// only stepping in the given directions onto tiles for which `passable` holds.
+    fn shortest_paths<D>(
+        &self,
+        start: Point2D<usize>,
+        goal: Point2D<usize>,
+        directions: impl IntoIterator<Item=D> + Clone,
+        passable: impl FnMut(&Self::GridItem) -> bool
+    ) -> Option<ShortestPaths<Point2D<usize>, usize>>
+        where D: Directional<Point2D<isize>>;
+}
+
+impl<G: GridLike> GridPathfinding for G {
+    fn shortest_paths<D>(
+        &self,
+        start: Point2D<usize>,
+        goal: Point2D<usize>,
+        directions: impl IntoIterator<Item=D> + Clone,
+        mut passable: impl FnMut(&Self::GridItem) -> bool
+    ) -> Option<ShortestPaths<Point2D<usize>, usize>>
+        where D: Directional<Point2D<isize>>
+    {
+        bfs(
+            once(start),
+            |&location| self.neighbours(location, directions.clone())
+                .filter(|(_, tile)| passable(tile))
+                .map(|(neighbour, _)| neighbour)
+                .collect_vec(),
+            |&location| location == goal
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter::once;
+
+    use crate::geometry::grid::dijkstra;
+
+    #[test]
+    fn free_steps_do_not_loop() {
+        let edges = [('s', 'n', 0), ('n', 's', 0), ('n', 'm', 0), ('m', 'n', 0), ('s', 'm', 0), ('m', 'g', 1)];
+        let successors = |&state: &char| edges
+            .iter()
+            .filter(move |&&(from, ..)| from == state)
+            .map(|&(_, to, cost)| (to, cost));
+
+        let paths = dijkstra(once('s'), successors, |&state| state == 'g').unwrap();
+        assert_eq!(1, paths.cost);
+        assert_eq!(vec!['s', 'm', 'g'], paths.path());
+        assert_eq!(2, paths.paths().count());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,151 @@
+use ahash::{HashSet, HashSetExt};
+
+use crate::geometry::{Area, CardinalDirection, Directional, Point2D, RotationDirection};
+
+use super::GridLike;
+
+/// A set of orthogonally connected tiles on a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    origin: Point2D<usize>,
+    cells: HashSet<Point2D<usize>>
+}
+
+impl Region {
+    /// The first cell of the region in reading order.
+    #[must_use]
+    pub const fn origin(&self) -> Point2D<usize> { self.origin }
+
+    pub fn cells(&self) -> impl Iterator<Item=Point2D<usize>> + '_ {
+        self.cells.iter().copied()
+    }
+
+    #[must_use]
+    pub fn contains(&self, location: Point2D<usize>) -> bool {
+        self.cells.contains(&location)
+    }
+
+    /// The amount of cells in the region.
+    #[must_use]
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The amount of cell edges that border a cell outside of the region.
+    #[must_use]
+    pub fn perimeter(&self) -> usize {
+        self.cells()
+            .flat_map(|cell| CardinalDirection::all().map(|direction| self.contains_step(cell, direction)))
+            .filter(|&inside| !inside)
+            .count()
+    }
+
+    /// The amount of straight sides of the fence around the region, including the fences around holes.
+    /// This is equal to the amount of corners of the region.
+    /// ```
+    /// # use aoc_lib::geometry::grid::{Grid, GridLike, GridRegions};
+    /// let grid = Grid::new(vec![
+    ///     vec![1, 1, 1],
+    ///     vec![1, 0, 1],
+    ///     vec![1, 1, 0]
+    /// ]).unwrap();
+    ///
+    /// let region = grid.region_at(grid.area().top_left(), |a, b| a == b);
+    /// assert_eq!(7, region.area());
+    /// assert_eq!(16, region.perimeter());
+    /// assert_eq!(10, region.sides());
+    /// ```
+    #[must_use]
+    pub fn sides(&self) -> usize {
+        self.cells()
+            .flat_map(|cell| CardinalDirection::all().map(move |direction| (cell, direction)))
+            .filter(|&(cell, direction)| {
+                let side = direction.rotate(RotationDirection::Right);
+                let ahead = self.contains_step(cell, direction);
+                let beside = self.contains_step(cell, side);
+                let diagonal = cell
+                    .checked_add::<isize>(direction.direction_vector())
+                    .is_some_and(|ahead| self.contains_step(ahead, side));
+
+                // Either an outer corner or an inner corner
+                (!ahead && !beside) || (ahead && beside && !diagonal)
+            })
+            .count()
+    }
+
+    /// The smallest area containing the entire region.
+    #[must_use]
+    pub fn bounds(&self) -> Area<usize> {
+        self.cells().fold(Area::from_corners(self.origin, self.origin), |bounds, cell| {
+            bounds.union(&Area::from_corners(cell, cell))
+        })
+    }
+
+    fn contains_step(&self, cell: Point2D<usize>, direction: CardinalDirection) -> bool {
+        cell.checked_add::<isize>(direction.direction_vector())
+            .is_some_and(|neighbour| self.contains(neighbour))
+    }
+}
+
+pub trait GridRegions: GridLike {
+    /// Flood fills the region containing `location`.
+    /// Neighbouring tiles belong to the same region when `connected` holds for them.
+    fn region_at(&self, location: Point2D<usize>, connected: impl FnMut(&Self::GridItem, &Self::GridItem) -> bool) -> Region;
+
+    /// Partitions the grid into regions, ordered by their origin.
+    /// Neighbouring tiles belong to the same region when `connected` holds for them,
+    /// this should be an equivalence relation.
+    fn regions(&self, connected: impl FnMut(&Self::GridItem, &Self::GridItem) -> bool) -> Vec<Region>;
+
+    /// Finds the tiles that can't be reached from outside the grid without passing through a wall.
+    /// Walls themselves are never enclosed.
+    fn enclosed(&self, is_wall: impl FnMut(&Self::GridItem) -> bool) -> HashSet<Point2D<usize>>;
+}
+
+impl<G: GridLike> GridRegions for G {
+    fn region_at(&self, location: Point2D<usize>, mut connected: impl FnMut(&Self::GridItem, &Self::GridItem) -> bool) -> Region {
+        let mut cells = HashSet::new();
+        let mut stack = vec![location];
+
+        while let Some(cell) = stack.pop() {
+            let Some(tile) = self.get(cell) else { continue };
+            if !cells.insert(cell) { continue }
+
+            stack.extend(self.neighbours(cell, CardinalDirection::all())
+                .filter(|&(neighbour, other)| !cells.contains(&neighbour) && connected(tile, other))
+                .map(|(neighbour, _)| neighbour));
+        }
+
+        Region { origin: location, cells }
+    }
+
+    fn regions(&self, mut connected: impl FnMut(&Self::GridItem, &Self::GridItem) -> bool) -> Vec<Region> {
+        let mut seen = HashSet::<Point2D<usize>>::new();
+        let mut regions = Vec::new();
+
+        for location in self.area() {
+            if seen.contains(&location) { continue }
+
+            let region = self.region_at(location, &mut connected);
+            seen.extend(region.cells());
+            regions.push(region);
+        }
+
+        regions
+    }
+
+    fn enclosed(&self, mut is_wall: impl FnMut(&Self::GridItem) -> bool) -> HashSet<Point2D<usize>> {
+        let mut outside = HashSet::<Point2D<usize>>::new();
+        let mut stack: Vec<Point2D<usize>> = self.area().edges().collect();
+
+        while let Some(cell) = stack.pop() {
+            if self.get(cell).is_none_or(&mut is_wall) || !outside.insert(cell) { continue }
+            stack.extend(self.neighbours(cell, CardinalDirection::all()).map(|(neighbour, _)| neighbour));
+        }
+
+        self.enumerate()
+            .filter(|&(location, tile)| !outside.contains(&location) && !is_wall(tile))
+            .map(|(location, _)| location)
+            .collect()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,316 @@
+use std::{collections::hash_map, fmt::{self, Display, Formatter}, hash::{Hash, Hasher}};
+
+use ahash::{HashMap, HashMapExt};
+use itertools::Itertools;
+
+use crate::geometry::{Area, Dimensions, Point2D, Point3D};
+
+use super::{Grid, GridLike};
+
+/// Points that can be used to index a [`SparseGrid`].
+/// The grid keeps track of the smallest and largest component of its points along every axis.
+pub trait SparsePoint: Clone + Eq + Hash {
+    /// The point containing the smallest of each component.
+    #[must_use]
+    fn component_min(&self, other: &Self) -> Self;
+
+    /// The point containing the largest of each component.
+    #[must_use]
+    fn component_max(&self, other: &Self) -> Self;
+
+    /// Whether any of the components is equal to the matching component of the other point.
+    fn shares_component(&self, other: &Self) -> bool;
+}
+
+impl<T: Ord + Clone + Hash> SparsePoint for Point2D<T> {
+    fn component_min(&self, Self(x2, y2): &Self) -> Self {
+        let Self(x, y) = self;
+        Self(x.min(x2).clone(), y.min(y2).clone())
+    }
+
+    fn component_max(&self, Self(x2, y2): &Self) -> Self {
+        let Self(x, y) = self;
+        Self(x.max(x2).clone(), y.max(y2).clone())
+    }
+
+    fn shares_component(&self, Self(x2, y2): &Self) -> bool {
+        let Self(x, y) = self;
+        x == x2 || y == y2
+    }
+}
+
+impl<T: Ord + Clone + Hash> SparsePoint for Point3D<T> {
+    fn component_min(&self, Self(x2, y2, z2): &Self) -> Self {
+        let Self(x, y, z) = self;
+        Self(x.min(x2).clone(), y.min(y2).clone(), z.min(z2).clone())
+    }
+
+    fn component_max(&self, Self(x2, y2, z2): &Self) -> Self {
+        let Self(x, y, z) = self;
+        Self(x.max(x2).clone(), y.max(y2).clone(), z.max(z2).clone())
+    }
+
+    fn shares_component(&self, Self(x2, y2, z2): &Self) -> bool {
+        let Self(x, y, z) = self;
+        x == x2 || y == y2 || z == z2
+    }
+}
+
+/// A grid without a fixed size that only stores the cells that are occupied.
+/// Unlike [`Grid`] its coordinates can be negative and it can grow in any direction.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T, P = Point2D<isize>> {
+    cells: HashMap<P, T>,
+    bounds: Option<(P, P)>
+}
+
+impl<T, P> Default for SparseGrid<T, P> {
+    fn default() -> Self {
+        Self { cells: HashMap::new(), bounds: None }
+    }
+}
+
+impl<T: PartialEq, P: SparsePoint> PartialEq for SparseGrid<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: Eq, P: SparsePoint> Eq for SparseGrid<T, P> {}
+
+impl<T: Hash, P: SparsePoint + Ord> Hash for SparseGrid<T, P> {
+    /// The cells are hashed in order of their location,
+    /// so grids containing the same cells have the same hash.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.cells.len().hash(state);
+        for cell in self.cells.iter().sorted_unstable_by_key(|&(location, _)| location) {
+            cell.hash(state);
+        }
+    }
+}
+
+impl<T, P: SparsePoint> SparseGrid<T, P> {
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    #[must_use]
+    pub fn len(&self) -> usize { self.cells.len() }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.cells.is_empty() }
+
+    /// The smallest and the largest corner of the box containing every occupied cell.
+    /// Returns `None` if the grid is empty.
+    #[must_use]
+    pub const fn bounds(&self) -> Option<&(P, P)> {
+        self.bounds.as_ref()
+    }
+
+    pub fn get(&self, location: &P) -> Option<&T> {
+        self.cells.get(location)
+    }
+
+    pub fn get_mut(&mut self, location: &P) -> Option<&mut T> {
+        self.cells.get_mut(location)
+    }
+
+    pub fn contains(&self, location: &P) -> bool {
+        self.cells.contains_key(location)
+    }
+
+    /// Occupies a cell, returning the value that was previously stored there.
+    pub fn insert(&mut self, location: P, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds.take() {
+            None => (location.clone(), location.clone()),
+            Some((min, max)) => (min.component_min(&location), max.component_max(&location))
+        });
+
+        self.cells.insert(location, value)
+    }
+
+    /// Clears a cell, returning the value that was stored there.
+    /// The bounds are only recomputed when the cell was on the edge of the bounds.
+    pub fn remove(&mut self, location: &P) -> Option<T> {
+        let value = self.cells.remove(location)?;
+
+        let on_edge = self.bounds
+            .as_ref()
+            .is_some_and(|(min, max)| location.shares_component(min) || location.shares_component(max));
+
+        if on_edge { self.recompute_bounds(); }
+        Some(value)
+    }
+
+    /// Only keeps the cells for which the predicate holds.
+    pub fn retain(&mut self, mut predicate: impl FnMut(&P, &T) -> bool) {
+        self.cells.retain(|location, value| predicate(location, value));
+        self.recompute_bounds();
+    }
+
+    /// Clears every cell, keeping the allocated memory.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.bounds = None;
+    }
+
+    fn recompute_bounds(&mut self) {
+        self.bounds = self.cells
+            .keys()
+            .fold(None, |bounds, location| Some(match bounds {
+                None => (location.clone(), location.clone()),
+                Some((min, max)) => (location.component_min(&min), location.component_max(&max))
+            }));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=(&P, &T)> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item=(&P, &mut T)> {
+        self.cells.iter_mut()
+    }
+
+    pub fn locations(&self) -> impl Iterator<Item=&P> {
+        self.cells.keys()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item=&T> {
+        self.cells.values()
+    }
+
+    pub fn map<U>(self, mut mapper: impl FnMut(&P, T) -> U) -> SparseGrid<U, P> {
+        let cells = self.cells
+            .into_iter()
+            .map(|(location, value)| {
+                let value = mapper(&location, value);
+                (location, value)
+            })
+            .collect();
+
+        SparseGrid { cells, bounds: self.bounds }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// The smallest area containing every occupied cell.
+    /// Returns `None` if the grid is empty.
+    #[must_use]
+    pub fn area(&self) -> Option<Area<isize>> {
+        self.bounds.map(|(min, max)| Area::from_corners(min, max))
+    }
+
+    /// Iterates over every location within the bounds of the grid row by row,
+    /// including the locations that are not occupied.
+    pub fn enumerate(&self) -> impl Iterator<Item=(Point2D<isize>, Option<&T>)> {
+        self.area()
+            .into_iter()
+            .flatten()
+            .map(|location| (location, self.get(&location)))
+    }
+
+    /// Iterates over the rows within the bounds of the grid.
+    pub fn iter_rows(&self) -> impl Iterator<Item=impl Iterator<Item=Option<&T>>> {
+        self.area().into_iter().flat_map(move |area| {
+            (area.top()..=area.bottom()).map(move |y| {
+                (area.left()..=area.right()).map(move |x| self.get(&Point2D(x, y)))
+            })
+        })
+    }
+
+    /// Converts the grid to a dense [`Grid`] covering its [area](Self::area).
+    /// The top left of the area is moved to the origin and unoccupied cells are filled with the default value.
+    /// ```
+    /// # use aoc_lib::geometry::{Point2D, grid::{GridLike, SparseGrid}};
+    /// let sparse: SparseGrid<u8> = [(Point2D(-1, -1), 1), (Point2D(1, 0), 2)].into_iter().collect();
+    /// let dense = sparse.to_grid();
+    ///
+    /// assert_eq!(vec![vec![&1, &0, &0], vec![&0, &0, &2]], dense.iter_rows().map(Iterator::collect::<Vec<_>>).collect::<Vec<_>>());
+    /// ```
+    #[must_use]
+    pub fn to_grid(&self) -> Grid<T>
+        where T: Default + Clone
+    {
+        if self.is_empty() { return Grid::empty(Dimensions(0, 0)) }
+
+        let cells = self.iter_rows()
+            .map(|row| row.map(|cell| cell.cloned().unwrap_or_default()).collect())
+            .collect();
+
+        Grid::new(cells).unwrap()
+    }
+}
+
+impl<T> From<Grid<T>> for SparseGrid<T> {
+    /// Occupies every cell of the grid, the top left of the grid is placed at the origin.
+    fn from(grid: Grid<T>) -> Self {
+        let area = grid.area();
+        area.into_iter()
+            .zip(grid)
+            .map(|(location, value)| (location.map(usize::cast_signed), value))
+            .collect()
+    }
+}
+
+impl<T, P: SparsePoint> FromIterator<(P, T)> for SparseGrid<T, P> {
+    fn from_iter<I: IntoIterator<Item=(P, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T, P: SparsePoint> Extend<(P, T)> for SparseGrid<T, P> {
+    fn extend<I: IntoIterator<Item=(P, T)>>(&mut self, iter: I) {
+        for (location, value) in iter {
+            self.insert(location, value);
+        }
+    }
+}
+
+impl<T, P> IntoIterator for SparseGrid<T, P> {
+    type Item = (P, T);
+    type IntoIter = hash_map::IntoIter<P, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    /// Renders the area of the grid, unoccupied cells are shown as ``.``.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let string = self.iter_rows()
+            .map(|row| row
+                .map(|cell| cell.map_or_else(|| String::from("."), ToString::to_string))
+                .join("")
+            )
+            .join("\n");
+
+        write!(f, "{string}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Point2D, grid::SparseGrid};
+
+    #[test]
+    fn remove_updates_bounds() {
+        let mut grid: SparseGrid<()> = [Point2D(0, 0), Point2D(1, 1), Point2D(2, 2), Point2D(4, 3)]
+            .into_iter()
+            .map(|location| (location, ()))
+            .collect();
+
+        grid.remove(&Point2D(1, 1));
+        assert_eq!(Some(&(Point2D(0, 0), Point2D(4, 3))), grid.bounds());
+
+        grid.remove(&Point2D(4, 3));
+        assert_eq!(Some(&(Point2D(0, 0), Point2D(2, 2))), grid.bounds());
+
+        grid.remove(&Point2D(0, 0));
+        assert_eq!(Some(&(Point2D(2, 2), Point2D(2, 2))), grid.bounds());
+
+        grid.remove(&Point2D(2, 2));
+        assert_eq!(None, grid.bounds());
+    }
+}
\ No newline at end of file
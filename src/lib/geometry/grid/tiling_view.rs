@@ -0,0 +1,99 @@
+use std::ops::Index;
+
+use crate::geometry::{Area, Directional, Point2D};
+
+use super::{Grid, GridLike};
+
+/// A view of a [`Grid`] that is repeated infinitely in every direction.
+/// Locations are signed, the original grid is the tile containing the origin.
+///
+/// Accessing a location on a view of an empty grid will panic.
+/// ```
+/// # use aoc_lib::geometry::{Point2D, grid::{Grid, TilingGridView}};
+/// let grid = Grid::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+/// let view = TilingGridView::new(&grid);
+///
+/// assert_eq!(&4, view.get(Point2D(-1, 3)));
+/// assert_eq!(Point2D(1, 1), view.base_location(Point2D(-1, 3)));
+/// assert_eq!(Point2D(-1, 1), view.tile(Point2D(-1, 3)));
+/// ```
+#[derive(Debug)]
+pub struct TilingGridView<'a, T> {
+    grid: &'a Grid<T>
+}
+
+impl<T> Clone for TilingGridView<'_, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<T> Copy for TilingGridView<'_, T> {}
+
+impl<'a, T> TilingGridView<'a, T> {
+    #[must_use]
+    pub const fn new(grid: &'a Grid<T>) -> Self {
+        Self { grid }
+    }
+
+    /// The area of the original grid.
+    #[must_use]
+    pub fn base_area(self) -> Area<usize> {
+        self.grid.area()
+    }
+
+    /// Maps a location onto the corresponding location within the original grid.
+    #[must_use]
+    pub const fn base_location(self, Point2D(x, y): Point2D<isize>) -> Point2D<usize> {
+        let (width, height) = self.tile_size();
+        Point2D(x.rem_euclid(width).cast_unsigned(), y.rem_euclid(height).cast_unsigned())
+    }
+
+    /// The index of the copy of the grid that contains the location.
+    #[must_use]
+    pub const fn tile(self, Point2D(x, y): Point2D<isize>) -> Point2D<isize> {
+        let (width, height) = self.tile_size();
+        Point2D(x.div_euclid(width), y.div_euclid(height))
+    }
+
+    const fn tile_size(self) -> (isize, isize) {
+        let dimensions = self.grid.dimensions;
+        (dimensions.width().cast_signed(), dimensions.height().cast_signed())
+    }
+
+    #[must_use]
+    pub fn get(self, location: Point2D<isize>) -> &'a T {
+        &self.grid[self.base_location(location)]
+    }
+
+    /// The neighbours of a location when taking a step in every direction.
+    /// Every location has a neighbour in every direction, because the grid never ends.
+    pub fn neighbours<D>(self, location: Point2D<isize>, directions: impl IntoIterator<Item=D>) -> impl Iterator<Item=(Point2D<isize>, &'a T)>
+        where D: Directional<Point2D<isize>>
+    {
+        directions.into_iter().map(move |direction| {
+            let neighbour = location + direction.direction_vector();
+            (neighbour, self.get(neighbour))
+        })
+    }
+
+    /// The neighbours of a location within the original grid, when the edges of the grid wrap around like a torus.
+    pub fn wrapping_neighbours<D>(self, location: Point2D<usize>, directions: impl IntoIterator<Item=D>) -> impl Iterator<Item=(Point2D<usize>, &'a T)>
+        where D: Directional<Point2D<isize>>
+    {
+        self.neighbours(location.map(usize::cast_signed), directions)
+            .map(move |(neighbour, tile)| (self.base_location(neighbour), tile))
+    }
+}
+
+impl<'a, T> From<&'a Grid<T>> for TilingGridView<'a, T> {
+    fn from(grid: &'a Grid<T>) -> Self {
+        Self::new(grid)
+    }
+}
+
+impl<T> Index<Point2D<isize>> for TilingGridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, location: Point2D<isize>) -> &Self::Output {
+        (*self).get(location)
+    }
+}
\ No newline at end of file
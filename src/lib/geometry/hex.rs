@@ -0,0 +1,278 @@
+use std::{fmt::{self, Display, Formatter}, iter::repeat_n, ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign}};
+
+use num::{Integer, One, PrimInt, Signed, Zero};
+
+use super::{Directional, HexDirection, Point2D, Point3D, PointyHexDirection};
+
+/// A tile on a 2D hex grid in axial coordinates.
+///
+/// The implicit third cube coordinate `s` is chosen such that `q + r + s = 0`.
+/// For more information on these coordinates,
+/// check out [this](https://www.redblobgames.com/grids/hexagons/#coordinates-axial) article on hexagonal grids.
+///
+/// Steps can be taken using either [`HexDirection`] for flat-topped hexes,
+/// or [`PointyHexDirection`] for pointy-topped hexes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct HexCoord<T = i32> {
+    pub q: T,
+    pub r: T
+}
+
+impl<T: Copy> Copy for HexCoord<T> {}
+
+/// The ways a hex grid can be laid out on a rectangular grid of offset coordinates.
+///
+/// The column layouts are used for flat-topped hexes where every other column is shoved down,
+/// the row layouts are used for pointy-topped hexes where every other row is shoved right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OffsetLayout {
+    OddColumns,
+    EvenColumns,
+    OddRows,
+    EvenRows
+}
+
+impl<T> HexCoord<T> {
+    pub const fn new(q: T, r: T) -> Self { Self { q, r } }
+
+    /// The hex located at the origin.
+    #[must_use]
+    pub fn zero() -> Self where T: Zero { Self::new(T::zero(), T::zero()) }
+
+    /// The third cube coordinate.
+    pub fn s(self) -> T where
+        T: Neg<Output=T> + Sub<Output=T>
+    {
+        -self.q - self.r
+    }
+
+    /// Converts the hex into cube coordinates.
+    pub fn cube(self) -> Point3D<T> where
+        T: Clone + Neg<Output=T> + Sub<Output=T>
+    {
+        let s = self.clone().s();
+        Point3D(self.q, self.r, s)
+    }
+
+    /// Converts a point in cube coordinates to a hex.
+    /// Returns `None` if the coordinates don't sum up to zero.
+    pub fn from_cube(Point3D(q, r, s): Point3D<T>) -> Option<Self> where
+        T: Clone + Zero + Add<Output=T>
+    {
+        (q.clone() + r.clone() + s).is_zero().then_some(Self::new(q, r))
+    }
+
+    /// The hex that is reached by taking one step in `direction`.
+    #[must_use]
+    pub fn step<D>(self, direction: D) -> Self where
+        T: Add<Output=T>,
+        D: Directional<Self>
+    {
+        self + direction.direction_vector()
+    }
+
+    /// The six hexes that border this hex.
+    pub fn neighbours(self) -> impl Iterator<Item=Self> where
+        T: Clone + Zero + One + Neg<Output=T> + Add<Output=T>
+    {
+        PointyHexDirection::all()
+            .into_iter()
+            .map(move |direction| self.clone().step(direction))
+    }
+
+    /// The amount of steps it takes to move between two hexes.
+    pub fn distance(self, other: Self) -> T where
+        T: Clone + One + Ord + Neg<Output=T> + Sub<Output=T> + Add<Output=T> + Div<Output=T>
+    {
+        self.cube().hex_distance(other.cube())
+    }
+
+    /// All hexes at exactly `radius` steps from this hex.
+    /// ```
+    /// # use aoc_lib::geometry::HexCoord;
+    /// let center = HexCoord::new(2, -1);
+    /// assert_eq!(1, center.ring(0).count());
+    /// assert_eq!(18, center.ring(3).count());
+    /// assert!(center.ring(3).all(|hex| hex.distance(center) == 3));
+    /// ```
+    pub fn ring(self, radius: usize) -> impl Iterator<Item=Self> where
+        T: Clone + Zero + One + Neg<Output=T> + Add<Output=T>
+    {
+        let start = repeat_n(PointyHexDirection::SouthWest, radius)
+            .fold(self.clone(), Self::step);
+
+        let ring = PointyHexDirection::all()
+            .into_iter()
+            .flat_map(move |direction| repeat_n(direction, radius))
+            .scan(start, |position, direction| {
+                let current = position.clone();
+                *position = current.clone().step(direction);
+                Some(current)
+            });
+
+        (radius == 0).then_some(self)
+            .into_iter()
+            .chain(ring)
+    }
+
+    /// All hexes within `radius` steps from this hex, ordered by distance.
+    pub fn spiral(self, radius: usize) -> impl Iterator<Item=Self> where
+        T: Clone + Zero + One + Neg<Output=T> + Add<Output=T>
+    {
+        (0..=radius).flat_map(move |radius| self.clone().ring(radius))
+    }
+
+    /// The hexes on the straight line between this hex and `other`, including both ends.
+    /// ```
+    /// # use aoc_lib::geometry::HexCoord;
+    /// let line: Vec<HexCoord> = HexCoord::zero().line_to(HexCoord::new(2, -2)).collect();
+    /// assert_eq!(vec![HexCoord::zero(), HexCoord::new(1, -1), HexCoord::new(2, -2)], line);
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    pub fn line_to(self, other: Self) -> impl Iterator<Item=Self> where
+        T: PrimInt + Signed
+    {
+        let steps = self.distance(other).to_usize().unwrap_or_default();
+        let [q1, r1, q2, r2] = [self.q, self.r, other.q, other.r]
+            .map(|component| component.to_f64().unwrap_or_default());
+
+        (0..=steps).map(move |step| {
+            let t = if steps == 0 { 0.0 } else { step as f64 / steps as f64 };
+            // Nudge the line off the edges between hexes to break ties consistently
+            Self::round((q2 - q1).mul_add(t, q1) + 1e-6, (r2 - r1).mul_add(t, r1) + 1e-6)
+        })
+    }
+
+    fn round(q: f64, r: f64) -> Self where
+        T: PrimInt + Signed
+    {
+        let s = -q - r;
+        let [mut rounded_q, mut rounded_r, rounded_s] = [q, r, s].map(f64::round);
+        let [dq, dr, ds] = [rounded_q - q, rounded_r - r, rounded_s - s].map(f64::abs);
+
+        if dq > dr && dq > ds { rounded_q = -rounded_r - rounded_s; }
+        else if dr > ds { rounded_r = -rounded_q - rounded_s; }
+
+        let convert = |component: f64| T::from(component).unwrap_or_else(T::zero);
+        Self::new(convert(rounded_q), convert(rounded_r))
+    }
+
+    /// Converts the hex to a column and row on a rectangular grid.
+    pub fn to_offset(self, layout: OffsetLayout) -> Point2D<T> where
+        T: Integer + Clone
+    {
+        let Self { q, r } = self;
+        match layout {
+            OffsetLayout::OddColumns => Point2D(q.clone(), r + half_floor(&q)),
+            OffsetLayout::EvenColumns => Point2D(q.clone(), r + half_ceil(q)),
+            OffsetLayout::OddRows => Point2D(q + half_floor(&r), r),
+            OffsetLayout::EvenRows => Point2D(q + half_ceil(r.clone()), r)
+        }
+    }
+
+    /// Converts a column and row on a rectangular grid back to a hex.
+    /// ```
+    /// # use aoc_lib::geometry::{HexCoord, OffsetLayout, Point2D};
+    /// let hex = HexCoord::new(-3, 5);
+    /// let offset = hex.to_offset(OffsetLayout::EvenRows);
+    ///
+    /// assert_eq!(Point2D(0, 5), offset);
+    /// assert_eq!(hex, HexCoord::from_offset(offset, OffsetLayout::EvenRows));
+    /// ```
+    pub fn from_offset(Point2D(column, row): Point2D<T>, layout: OffsetLayout) -> Self where
+        T: Integer + Clone
+    {
+        match layout {
+            OffsetLayout::OddColumns => Self::new(column.clone(), row - half_floor(&column)),
+            OffsetLayout::EvenColumns => Self::new(column.clone(), row - half_ceil(column)),
+            OffsetLayout::OddRows => Self::new(column - half_floor(&row), row),
+            OffsetLayout::EvenRows => Self::new(column - half_ceil(row.clone()), row)
+        }
+    }
+}
+
+fn half_floor<T: Integer>(value: &T) -> T {
+    value.div_floor(&(T::one() + T::one()))
+}
+
+fn half_ceil<T: Integer>(value: T) -> T {
+    half_floor(&(value + T::one()))
+}
+
+impl<T> Directional<HexCoord<T>> for HexDirection
+    where T: Zero + One + Neg<Output=T>
+{
+    fn direction_vector(self) -> HexCoord<T> {
+        let Point3D(q, r, _) = self.direction_vector();
+        HexCoord::new(q, r)
+    }
+}
+
+impl<T> Directional<HexCoord<T>> for PointyHexDirection
+    where T: Zero + One + Neg<Output=T>
+{
+    fn direction_vector(self) -> HexCoord<T> {
+        let Point3D(q, r, _) = self.direction_vector();
+        HexCoord::new(q, r)
+    }
+}
+
+impl<T: Add<Output=T>> Add for HexCoord<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl<T: Sub<Output=T>> Sub for HexCoord<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl<T: Mul<Output=T> + Clone> Mul<T> for HexCoord<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.q * rhs.clone(), self.r * rhs)
+    }
+}
+
+impl<T: Neg<Output=T>> Neg for HexCoord<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+impl<T: AddAssign> AddAssign for HexCoord<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.q += rhs.q;
+        self.r += rhs.r;
+    }
+}
+
+impl<T: SubAssign> SubAssign for HexCoord<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.q -= rhs.q;
+        self.r -= rhs.r;
+    }
+}
+
+impl<T> From<HexCoord<T>> for Point3D<T> where
+    T: Clone + Neg<Output=T> + Sub<Output=T>
+{
+    fn from(hex: HexCoord<T>) -> Self {
+        hex.cube()
+    }
+}
+
+impl<T: Display> Display for HexCoord<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.q, self.r)
+    }
+}
\ No newline at end of file
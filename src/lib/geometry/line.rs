@@ -0,0 +1,109 @@
+use num::{Integer, Zero, rational::Ratio};
+
+use super::{Point2D, Point3D};
+
+/// A line in parametric form, consisting of all the points `origin + t * direction`.
+/// When only considering `t >= 0` the line becomes a ray.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line<P> {
+    pub origin: P,
+    pub direction: P
+}
+
+/// The exact point where two lines cross.
+/// `t` and `u` are the parameters at which the first and second line respectively pass through the point.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Intersection<P, S> {
+    pub point: P,
+    pub t: S,
+    pub u: S
+}
+
+impl<P, S> Intersection<P, S> {
+    /// Returns whether the intersection lies ahead of the origin of both lines.
+    /// This is the case when the lines are treated as rays.
+    pub fn is_forward(&self) -> bool where
+        S: Zero + PartialOrd
+    {
+        self.t >= S::zero() && self.u >= S::zero()
+    }
+}
+
+impl<T> Line<Point2D<T>> where
+    T: Integer + Clone
+{
+    /// The point on the line at parameter `t`.
+    pub fn at(&self, t: &Ratio<T>) -> Point2D<Ratio<T>> {
+        let position = |origin: T, direction: T| Ratio::from_integer(origin) + t * direction;
+        let Point2D(x, y) = self.origin.clone();
+        let Point2D(dx, dy) = self.direction.clone();
+
+        Point2D(position(x, dx), position(y, dy))
+    }
+
+    /// Computes the exact point where two lines cross.
+    /// Returns `None` if the lines are parallel, this includes lines that overlap.
+    /// ```
+    /// # use aoc_lib::geometry::{Line, Point2D};
+    /// # use num::rational::Ratio;
+    /// let a = Line { origin: Point2D(0, 0), direction: Point2D(2, 2) };
+    /// let b = Line { origin: Point2D(0, 3), direction: Point2D(1, -1) };
+    ///
+    /// let intersection = a.intersect(&b).unwrap();
+    /// assert_eq!(Point2D(Ratio::new(3, 2), Ratio::new(3, 2)), intersection.point);
+    /// assert_eq!(Ratio::new(3, 4), intersection.t);
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Option<Intersection<Point2D<Ratio<T>>, Ratio<T>>> {
+        let denominator = self.direction.clone().cross(other.direction.clone());
+        if denominator.is_zero() { return None }
+
+        let offset = other.origin.clone() - self.origin.clone();
+        let t = Ratio::new(offset.clone().cross(other.direction.clone()), denominator.clone());
+        let u = Ratio::new(offset.cross(self.direction.clone()), denominator);
+
+        Some(Intersection { point: self.at(&t), t, u })
+    }
+
+    /// Computes the exact point where two rays cross.
+    /// Returns `None` if the rays are parallel or the lines cross behind either of the origins.
+    pub fn intersect_rays(&self, other: &Self) -> Option<Intersection<Point2D<Ratio<T>>, Ratio<T>>> {
+        self.intersect(other)
+            .filter(Intersection::is_forward)
+    }
+}
+
+impl<T> Line<Point3D<T>> where
+    T: Integer + Clone
+{
+    /// The point on the line at parameter `t`.
+    pub fn at(&self, t: &Ratio<T>) -> Point3D<Ratio<T>> {
+        let position = |origin: T, direction: T| Ratio::from_integer(origin) + t * direction;
+        let Point3D(x, y, z) = self.origin.clone();
+        let Point3D(dx, dy, dz) = self.direction.clone();
+
+        Point3D(position(x, dx), position(y, dy), position(z, dz))
+    }
+
+    /// Computes the exact point where two lines cross.
+    /// Returns `None` if the lines are parallel or skew.
+    pub fn intersect(&self, other: &Self) -> Option<Intersection<Point3D<Ratio<T>>, Ratio<T>>> {
+        let normal = self.direction.clone().cross(other.direction.clone());
+        let denominator = normal.clone().dot(normal.clone());
+        if denominator.is_zero() { return None }
+
+        let offset = other.origin.clone() - self.origin.clone();
+        if !offset.clone().dot(normal.clone()).is_zero() { return None }
+
+        let t = Ratio::new(offset.clone().cross(other.direction.clone()).dot(normal.clone()), denominator.clone());
+        let u = Ratio::new(offset.cross(self.direction.clone()).dot(normal), denominator);
+
+        Some(Intersection { point: self.at(&t), t, u })
+    }
+
+    /// Computes the exact point where two rays cross.
+    /// Returns `None` if the rays are parallel, skew or the lines cross behind either of the origins.
+    pub fn intersect_rays(&self, other: &Self) -> Option<Intersection<Point3D<Ratio<T>>, Ratio<T>>> {
+        self.intersect(other)
+            .filter(Intersection::is_forward)
+    }
+}
\ No newline at end of file
@@ -5,11 +5,23 @@ mod point2d;
 mod dimensions;
 mod area;
 mod point3d;
+mod point_n;
 mod polygon;
+mod line;
+mod transform;
+mod hex;
+mod cuboid;
+mod compression;
 
 pub use point2d::*;
 pub use point3d::*;
+pub use point_n::*;
 pub use direction::*;
 pub use dimensions::*;
 pub use area::*;
-pub use polygon::*;
\ No newline at end of file
+pub use polygon::*;
+pub use line::*;
+pub use transform::*;
+pub use hex::*;
+pub use cuboid::*;
+pub use compression::*;
\ No newline at end of file
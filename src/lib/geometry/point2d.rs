@@ -1,12 +1,12 @@
 use derive_more;
-use std::{ops::{Add, Sub}, fmt::{Display, Formatter, self}, cmp::minmax};
+use std::{ops::{Add, Sub, Mul, Neg}, fmt::{Display, Formatter, self}, cmp::{minmax, Ordering}, iter};
 
 use nom::{character::complete::{char, space0}, sequence::separated_pair, Parser};
-use num::{clamp, Zero, One};
+use num::{clamp, Zero, One, Signed, CheckedAdd, CheckedSub, CheckedMul, traits::{SaturatingAdd, SaturatingSub, SaturatingMul, WrappingAdd, WrappingSub, WrappingMul}};
 
 use crate::parsing::{TextParserResult, Parsable, parens, Map2};
 
-use super::{Dimensions, Directional, CardinalDirection};
+use super::{Dimensions, Directional, CardinalDirection, OrdinalDirection, Direction2D, RotationDirection, Axis, Transform2D};
 
 #[derive(
     Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord,
@@ -35,14 +35,120 @@ impl<T> Point2D<T> {
     /// Perform a termwise checked addition with another point.
     /// Returns `None` if any component overflows.
     pub fn checked_add<U>(self, rhs: Point2D<U>) -> Option<Self>
-        where U: TryInto<T> + Add<Output=U>,
+        where U: TryInto<T> + CheckedAdd,
               T: TryInto<U>
     {
-        let add = |a: T, b: U| (a.try_into().ok()? + b).try_into().ok();
+        self.try_zip_with(rhs, |a, b| {
+            let a: U = a.try_into().ok()?;
+            a.checked_add(&b)?.try_into().ok()
+        })
+    }
+
+    /// Perform a termwise checked subtraction with another point.
+    /// Returns `None` if any component overflows.
+    pub fn checked_sub<U>(self, rhs: Point2D<U>) -> Option<Self>
+        where U: TryInto<T> + CheckedSub,
+              T: TryInto<U>
+    {
+        self.try_zip_with(rhs, |a, b| {
+            let a: U = a.try_into().ok()?;
+            a.checked_sub(&b)?.try_into().ok()
+        })
+    }
+
+    /// Perform a termwise checked multiplication with another point.
+    /// Returns `None` if any component overflows.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self>
+        where T: CheckedMul
+    {
+        self.try_zip_with(rhs, |a, b| a.checked_mul(&b))
+    }
+
+    /// Multiply both components by a scalar.
+    /// Returns `None` if any component overflows.
+    /// ```
+    /// # use aoc_lib::geometry::Point2D;
+    /// assert_eq!(Some(Point2D(20u8, 40)), Point2D(2u8, 4).checked_scale(10));
+    /// assert_eq!(None, Point2D(2u8, 40).checked_scale(10));
+    /// ```
+    pub fn checked_scale(self, factor: T) -> Option<Self>
+        where T: CheckedMul + Clone
+    {
+        self.checked_mul(Self(factor.clone(), factor))
+    }
 
-        let x = add(self.0, rhs.0)?;
-        let y = add(self.1, rhs.1)?;
-        Some(Self(x, y))
+    /// Perform a termwise addition that saturates at the numeric bounds of `T`.
+    #[must_use]
+    pub fn saturating_add(self, rhs: Self) -> Self
+        where T: SaturatingAdd
+    {
+        self.zip_with(rhs, |a, b| a.saturating_add(&b))
+    }
+
+    /// Perform a termwise subtraction that saturates at the numeric bounds of `T`.
+    #[must_use]
+    pub fn saturating_sub(self, rhs: Self) -> Self
+        where T: SaturatingSub
+    {
+        self.zip_with(rhs, |a, b| a.saturating_sub(&b))
+    }
+
+    /// Perform a termwise multiplication that saturates at the numeric bounds of `T`.
+    #[must_use]
+    pub fn saturating_mul(self, rhs: Self) -> Self
+        where T: SaturatingMul
+    {
+        self.zip_with(rhs, |a, b| a.saturating_mul(&b))
+    }
+
+    /// Multiply both components by a scalar, saturating at the numeric bounds of `T`.
+    #[must_use]
+    pub fn saturating_scale(self, factor: T) -> Self
+        where T: SaturatingMul + Clone
+    {
+        self.saturating_mul(Self(factor.clone(), factor))
+    }
+
+    /// Perform a termwise addition that wraps around at the numeric bounds of `T`.
+    #[must_use]
+    pub fn wrapping_add(self, rhs: Self) -> Self
+        where T: WrappingAdd
+    {
+        self.zip_with(rhs, |a, b| a.wrapping_add(&b))
+    }
+
+    /// Perform a termwise subtraction that wraps around at the numeric bounds of `T`.
+    #[must_use]
+    pub fn wrapping_sub(self, rhs: Self) -> Self
+        where T: WrappingSub
+    {
+        self.zip_with(rhs, |a, b| a.wrapping_sub(&b))
+    }
+
+    /// Perform a termwise multiplication that wraps around at the numeric bounds of `T`.
+    #[must_use]
+    pub fn wrapping_mul(self, rhs: Self) -> Self
+        where T: WrappingMul
+    {
+        self.zip_with(rhs, |a, b| a.wrapping_mul(&b))
+    }
+
+    /// Multiply both components by a scalar, wrapping around at the numeric bounds of `T`.
+    #[must_use]
+    pub fn wrapping_scale(self, factor: T) -> Self
+        where T: WrappingMul + Clone
+    {
+        self.wrapping_mul(Self(factor.clone(), factor))
+    }
+
+    fn zip_with<U, V>(self, Point2D(x2, y2): Point2D<U>, mut f: impl FnMut(T, U) -> V) -> Point2D<V> {
+        let Self(x, y) = self;
+        Point2D(f(x, x2), f(y, y2))
+    }
+
+    fn try_zip_with<U, V>(self, Point2D(x2, y2): Point2D<U>, mut f: impl FnMut(T, U) -> Option<V>) -> Option<Point2D<V>> {
+        let Self(x, y) = self;
+        Some(Point2D(f(x, x2)?, f(y, y2)?))
     }
 
     /// The distance between two points when using the
@@ -57,6 +163,87 @@ impl<T> Point2D<T> {
         max_x - min_x + max_y - min_y
     }
 
+    /// The distance between two points when using the
+    /// [chebyshev distance function](https://en.wikipedia.org/wiki/Chebyshev_distance).
+    /// This is the amount of steps required to move between the points when diagonal steps are allowed.
+    pub fn chebyshev_distance(self, Self(x2, y2): Self) -> T
+        where T: Ord + Sub<Output=T>
+    {
+        let Self(x, y) = self;
+        let [min_x, max_x] = minmax(x, x2);
+        let [min_y, max_y] = minmax(y, y2);
+
+        (max_x - min_x).max(max_y - min_y)
+    }
+
+    /// The square of the euclidean distance between two points.
+    /// This avoids the square root so that distances can be compared exactly.
+    pub fn squared_euclidean_distance(self, Self(x2, y2): Self) -> T
+        where T: Clone + Ord + Sub<Output=T> + Add<Output=T> + Mul<Output=T>
+    {
+        let Self(x, y) = self;
+        let [min_x, max_x] = minmax(x, x2);
+        let [min_y, max_y] = minmax(y, y2);
+        let (dx, dy) = (max_x - min_x, max_y - min_y);
+
+        dx.clone() * dx + dy.clone() * dy
+    }
+
+    /// Iterates over the points on the line from this point to `other` using
+    /// [Bresenham's line algorithm](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm).
+    /// Both endpoints are included.
+    /// ```
+    /// # use aoc_lib::geometry::Point2D;
+    /// let line: Vec<Point2D> = Point2D(0, 0).line_to(Point2D(3, -1)).collect();
+    /// assert_eq!(vec![Point2D(0, 0), Point2D(1, 0), Point2D(2, -1), Point2D(3, -1)], line);
+    /// ```
+    pub fn line_to(self, Self(x2, y2): Self) -> impl Iterator<Item=Self>
+        where T: Clone + Ord + Signed
+    {
+        let Self(mut x, mut y) = self;
+        let (dx, dy) = ((x2.clone() - x.clone()).abs(), -(y2.clone() - y.clone()).abs());
+        let (step_x, step_y) = ((x2.clone() - x.clone()).signum(), (y2.clone() - y.clone()).signum());
+        let mut error = dx.clone() + dy.clone();
+        let mut done = false;
+
+        iter::from_fn(move || {
+            if done { return None }
+
+            let current = Self(x.clone(), y.clone());
+            done = x == x2 && y == y2;
+
+            let doubled_error = error.clone() + error.clone();
+            if doubled_error >= dy {
+                error = error.clone() + dy.clone();
+                x = x.clone() + step_x.clone();
+            }
+
+            if doubled_error <= dx {
+                error = error.clone() + dx.clone();
+                y = y.clone() + step_y.clone();
+            }
+
+            Some(current)
+        })
+    }
+
+    pub fn dot(self, Self(x2, y2): Self) -> T
+        where T: Mul<Output=T> + Add<Output=T>
+    {
+        let Self(x, y) = self;
+        x * x2 + y * y2
+    }
+
+    /// Computes the 2D cross product of two vectors.
+    /// This is the z component of the cross product when both vectors are placed in 3D space,
+    /// its sign indicates whether `other` lies clockwise or counter-clockwise of `self`.
+    pub fn cross(self, Self(x2, y2): Self) -> T
+        where T: Mul<Output=T> + Sub<Output=T>
+    {
+        let Self(x, y) = self;
+        x * y2 - y * x2
+    }
+
     // Clamps the individual components of the point
     #[must_use]
     pub fn clamp(self, min: T, max: T) -> Self
@@ -72,7 +259,7 @@ impl<T> Point2D<T> {
     /// If this calculation would overflow `T`, the neighbours are not included in the list.
     pub fn neighbours<U, D>(self, directions: impl IntoIterator<Item=D>) -> impl Iterator<Item=Self>
         where T: Clone + TryFrom<U>,
-              U: TryFrom<T> + Add<Output=U>,
+              U: TryFrom<T> + CheckedAdd,
               D: Directional<Point2D<U>>
     {
         directions.into_iter()
@@ -80,17 +267,77 @@ impl<T> Point2D<T> {
             .filter_map(move |direction| self.clone().checked_add(direction))
     }
 
-    /// Calculate the relative [`CardinalDirection`] from one point to another
-    /// Returns `None` if the points are the same or do not lie on the same line
-    pub fn direction_to(self, Self(x2, y2): Self) -> Option<CardinalDirection>
-        where T: Ord
+    /// Rotates the point a quarter turn around the origin.
+    #[must_use]
+    pub fn rotate(self, direction: RotationDirection) -> Self
+        where T: Clone + Zero + One + Neg<Output=T> + Mul<Output=T>
     {
+        Transform2D::rotation(direction).apply(self)
+    }
+
+    /// Rotates the point a quarter turn around `pivot`.
+    #[must_use]
+    pub fn rotate_around(self, pivot: Self, direction: RotationDirection) -> Self
+        where T: Clone + Zero + One + Neg<Output=T> + Mul<Output=T>
+    {
+        Transform2D::rotation(direction).around(pivot).apply(self)
+    }
+
+    /// Mirrors the point across an axis through the origin.
+    #[must_use]
+    pub fn reflect(self, axis: Axis) -> Self
+        where T: Clone + Zero + One + Neg<Output=T> + Mul<Output=T>
+    {
+        Transform2D::reflection(axis).apply(self)
+    }
+
+    /// Mirrors the point across an axis through `pivot`.
+    #[must_use]
+    pub fn reflect_around(self, pivot: Self, axis: Axis) -> Self
+        where T: Clone + Zero + One + Neg<Output=T> + Mul<Output=T>
+    {
+        Transform2D::reflection(axis).around(pivot).apply(self)
+    }
+
+    /// Calculate the relative [`Direction2D`] from one point to another.
+    /// Returns `None` if the points are the same or do not lie on the same horizontal, vertical or diagonal line.
+    /// ```
+    /// # use aoc_lib::geometry::{CardinalDirection, Direction2D, OrdinalDirection, Point2D};
+    /// assert_eq!(Some(Direction2D::Cardinal(CardinalDirection::North)), Point2D(1, 5).direction_to(Point2D(1, 2)));
+    /// assert_eq!(Some(Direction2D::Ordinal(OrdinalDirection::SouthEast)), Point2D(1, 2).direction_to(Point2D(4, 5)));
+    /// assert_eq!(None, Point2D(1, 2).direction_to(Point2D(4, 6)));
+    /// ```
+    pub fn direction_to(self, Self(x2, y2): Self) -> Option<Direction2D>
+        where T: Ord + Sub<Output=T>
+    {
+        use CardinalDirection as Card;
+        use OrdinalDirection as Ord;
+
         let Self(x, y) = self;
-        if x == x2 && y < y2 { Some(CardinalDirection::South) }
-        else if x == x2 && y > y2 { Some(CardinalDirection::North) }
-        else if x < x2 && y == y2 { Some(CardinalDirection::East) }
-        else if x > x2 && y == y2 { Some(CardinalDirection::West) }
-        else { None }
+        match (x.cmp(&x2), y.cmp(&y2)) {
+            (Ordering::Equal, Ordering::Equal) => None,
+            (Ordering::Equal, Ordering::Less) => Some(Direction2D::Cardinal(Card::South)),
+            (Ordering::Equal, Ordering::Greater) => Some(Direction2D::Cardinal(Card::North)),
+            (Ordering::Less, Ordering::Equal) => Some(Direction2D::Cardinal(Card::East)),
+            (Ordering::Greater, Ordering::Equal) => Some(Direction2D::Cardinal(Card::West)),
+            (horizontal, vertical) => {
+                let [min_x, max_x] = minmax(x, x2);
+                let [min_y, max_y] = minmax(y, y2);
+                if max_x - min_x != max_y - min_y { return None }
+
+                Some(Direction2D::Ordinal(match (horizontal, vertical) {
+                    (Ordering::Less, Ordering::Less) => Ord::SouthEast,
+                    (Ordering::Less, _) => Ord::NorthEast,
+                    (_, Ordering::Less) => Ord::SouthWest,
+                    _ => Ord::NorthWest
+                }))
+            }
+        }
+    }
+
+    /// Maps the individual components of the point.
+    pub fn map<U>(self, mut mapper: impl FnMut(T) -> U) -> Point2D<U> {
+        Point2D(mapper(self.0), mapper(self.1))
     }
 
     /// Attempts to map a `Point2D<T>` to a `Point2D<U>`.
@@ -1,12 +1,12 @@
-use std::{fmt::{Display, Formatter, self}, ops::{Add, Sub, Mul, Div}, cmp::minmax};
+use std::{fmt::{Display, Formatter, self}, ops::{Add, Sub, Mul, Div, Neg}, cmp::minmax};
 use derive_more;
 
 use nom::{sequence::terminated, character::complete::{char, space0}, Parser};
-use num::{Zero, One};
+use num::{Zero, One, CheckedAdd, CheckedSub, CheckedMul, traits::{real::Real, SaturatingAdd, SaturatingSub, SaturatingMul, WrappingAdd, WrappingSub, WrappingMul}};
 
 use crate::parsing::{Parsable, Map3, parens};
 
-use super::Directional;
+use super::{Directional, Transform3D};
 
 #[derive(
     Debug, Clone, Default, Eq, PartialEq, Hash, PartialOrd, Ord,
@@ -59,7 +59,7 @@ impl<T> Point3D<T> {
     /// If this calculation would overflow `T`, the neighbours are not included in the list.
     pub fn neighbours<U, D>(self, directions: impl IntoIterator<Item=D>) -> impl Iterator<Item=Self> where
         T: Clone + TryFrom<U>,
-        U: Add<Output = U> + TryFrom<T>,
+        U: CheckedAdd + TryFrom<T>,
         D: Directional<Point3D<U>>
     {
         directions.into_iter()
@@ -67,19 +67,149 @@ impl<T> Point3D<T> {
             .filter_map(move |direction| self.clone().checked_add(direction))
     }
 
+    /// Produces the point in each of the 24 orientations of 3D space.
+    /// See [`Transform3D::rotations`] to apply the same orientation to multiple points.
+    /// ```
+    /// # use aoc_lib::geometry::Point3D;
+    /// # use std::collections::HashSet;
+    /// let orientations: HashSet<Point3D> = Point3D(1, 2, 3).rotations().collect();
+    /// assert_eq!(24, orientations.len());
+    /// assert!(orientations.contains(&Point3D(-2, 1, 3)));
+    /// ```
+    pub fn rotations(self) -> impl Iterator<Item=Self> where
+        T: Clone + Zero + One + Neg<Output=T> + Mul<Output=T>
+    {
+        Transform3D::rotations().map(move |rotation| rotation.apply(self.clone()))
+    }
+
     /// Perform a termwise checked addition with another point.
     /// Returns `None` if any component overflows.
-    pub fn checked_add<U>(self, Point3D(x2, y2, z2): Point3D<U>) -> Option<Self> where
-        U: TryFrom<T> + Add<Output=U>,
+    pub fn checked_add<U>(self, rhs: Point3D<U>) -> Option<Self> where
+        U: TryFrom<T> + CheckedAdd,
         T: TryFrom<U>
     {
-        let add = |a: T, b: U| T::try_from(U::try_from(a).ok()? + b).ok();
+        self.try_zip_with(rhs, |a, b| T::try_from(U::try_from(a).ok()?.checked_add(&b)?).ok())
+    }
+
+    /// Perform a termwise checked subtraction with another point.
+    /// Returns `None` if any component overflows.
+    pub fn checked_sub<U>(self, rhs: Point3D<U>) -> Option<Self> where
+        U: TryFrom<T> + CheckedSub,
+        T: TryFrom<U>
+    {
+        self.try_zip_with(rhs, |a, b| T::try_from(U::try_from(a).ok()?.checked_sub(&b)?).ok())
+    }
+
+    /// Perform a termwise checked multiplication with another point.
+    /// Returns `None` if any component overflows.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> where
+        T: CheckedMul
+    {
+        self.try_zip_with(rhs, |a, b| a.checked_mul(&b))
+    }
+
+    /// Multiply all components by a scalar.
+    /// Returns `None` if any component overflows.
+    pub fn checked_scale(self, factor: T) -> Option<Self> where
+        T: CheckedMul + Clone
+    {
+        self.checked_mul(Self(factor.clone(), factor.clone(), factor))
+    }
+
+    /// Perform a termwise addition that saturates at the numeric bounds of `T`.
+    #[must_use]
+    pub fn saturating_add(self, rhs: Self) -> Self where
+        T: SaturatingAdd
+    {
+        self.zip_with(rhs, |a, b| a.saturating_add(&b))
+    }
+
+    /// Perform a termwise subtraction that saturates at the numeric bounds of `T`.
+    #[must_use]
+    pub fn saturating_sub(self, rhs: Self) -> Self where
+        T: SaturatingSub
+    {
+        self.zip_with(rhs, |a, b| a.saturating_sub(&b))
+    }
+
+    /// Perform a termwise multiplication that saturates at the numeric bounds of `T`.
+    #[must_use]
+    pub fn saturating_mul(self, rhs: Self) -> Self where
+        T: SaturatingMul
+    {
+        self.zip_with(rhs, |a, b| a.saturating_mul(&b))
+    }
+
+    /// Multiply all components by a scalar, saturating at the numeric bounds of `T`.
+    #[must_use]
+    pub fn saturating_scale(self, factor: T) -> Self where
+        T: SaturatingMul + Clone
+    {
+        self.saturating_mul(Self(factor.clone(), factor.clone(), factor))
+    }
+
+    /// Perform a termwise addition that wraps around at the numeric bounds of `T`.
+    #[must_use]
+    pub fn wrapping_add(self, rhs: Self) -> Self where
+        T: WrappingAdd
+    {
+        self.zip_with(rhs, |a, b| a.wrapping_add(&b))
+    }
+
+    /// Perform a termwise subtraction that wraps around at the numeric bounds of `T`.
+    #[must_use]
+    pub fn wrapping_sub(self, rhs: Self) -> Self where
+        T: WrappingSub
+    {
+        self.zip_with(rhs, |a, b| a.wrapping_sub(&b))
+    }
+
+    /// Perform a termwise multiplication that wraps around at the numeric bounds of `T`.
+    #[must_use]
+    pub fn wrapping_mul(self, rhs: Self) -> Self where
+        T: WrappingMul
+    {
+        self.zip_with(rhs, |a, b| a.wrapping_mul(&b))
+    }
+
+    /// Multiply all components by a scalar, wrapping around at the numeric bounds of `T`.
+    #[must_use]
+    pub fn wrapping_scale(self, factor: T) -> Self where
+        T: WrappingMul + Clone
+    {
+        self.wrapping_mul(Self(factor.clone(), factor.clone(), factor))
+    }
+
+    /// Maps the individual components of the point.
+    /// This can be used to move to [`num::BigInt`] components when the coordinates get too large.
+    /// ```
+    /// # use aoc_lib::geometry::Point3D;
+    /// # use num::BigInt;
+    /// let point = Point3D(i64::MAX, 1, 2).map(BigInt::from);
+    /// let expected = Point3D(BigInt::from(i64::MAX) * 2, BigInt::from(2), BigInt::from(4));
+    /// assert_eq!(expected, point.clone() + point);
+    /// ```
+    pub fn map<U>(self, mut mapper: impl FnMut(T) -> U) -> Point3D<U> {
+        let Self(x, y, z) = self;
+        Point3D(mapper(x), mapper(y), mapper(z))
+    }
+
+    /// Attempts to map a `Point3D<T>` to a `Point3D<U>`.
+    pub fn try_map<U>(self) -> Result<Point3D<U>, T::Error> where
+        T: TryInto<U>
+    {
         let Self(x, y, z) = self;
+        Ok(Point3D(x.try_into()?, y.try_into()?, z.try_into()?))
+    }
 
-        let x = add(x, x2)?;
-        let y = add(y, y2)?;
-        let z = add(z, z2)?;
-        Some(Self(x, y, z))
+    fn zip_with<U, V>(self, Point3D(x2, y2, z2): Point3D<U>, mut f: impl FnMut(T, U) -> V) -> Point3D<V> {
+        let Self(x, y, z) = self;
+        Point3D(f(x, x2), f(y, y2), f(z, z2))
+    }
+
+    fn try_zip_with<U, V>(self, Point3D(x2, y2, z2): Point3D<U>, mut f: impl FnMut(T, U) -> Option<V>) -> Option<Point3D<V>> {
+        let Self(x, y, z) = self;
+        Some(Point3D(f(x, x2)?, f(y, y2)?, f(z, z2)?))
     }
 
     pub fn dot(self, other: Self) -> T where
@@ -91,12 +221,43 @@ impl<T> Point3D<T> {
         x1 * x2 + y1 * y2 + z1 * z2
     }
 
+    /// Computes the [cross product](https://en.wikipedia.org/wiki/Cross_product) of two vectors.
+    /// The result is perpendicular to both vectors, and zero if they are parallel.
+    /// ```
+    /// # use aoc_lib::geometry::Point3D;
+    /// assert_eq!(Point3D(0, 0, 1), Point3D(1, 0, 0).cross(Point3D(0, 1, 0)));
+    /// assert_eq!(Point3D::zero(), Point3D(2, 4, 6).cross(Point3D(1, 2, 3)));
+    /// ```
+    #[must_use]
+    pub fn cross(self, other: Self) -> Self where
+        T: Mul<Output=T> + Sub<Output=T> + Clone
+    {
+        let Self(x1, y1, z1) = self;
+        let Self(x2, y2, z2) = other;
+
+        Self(
+            y1.clone() * z2.clone() - z1.clone() * y2.clone(),
+            z1 * x2.clone() - x1.clone() * z2,
+            x1 * y2 - y1 * x2
+        )
+    }
+
+    /// The length of the vector using the [euclidean norm](https://en.wikipedia.org/wiki/Norm_(mathematics)#Euclidean_norm).
+    pub fn euclidean_norm(self) -> T where
+        T: Real
+    {
+        self.dot(self).sqrt()
+    }
+
+    /// The length of the vector using the manhattan norm.
     pub fn magnitude(self) -> T where
         T: Zero + Ord + Sub<Output=T>
     {
         self.manhattan_distance(Self::zero())
     }
 
+    /// Divides the vector by its [manhattan magnitude](Self::magnitude).
+    /// Note that this is not the unit vector in euclidean space.
     #[must_use]
     pub fn normalized(self) -> Self where
         T: Zero + Ord + Copy + Sub<Output=T>,
@@ -0,0 +1,241 @@
+use std::{array, cmp::minmax, fmt::{self, Display, Formatter}, iter::once, ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign}};
+
+use itertools::Itertools;
+use nom::{character::complete::{char, space0}, combinator::map_opt, multi::count, sequence::{preceded, terminated}, Parser};
+use num::{CheckedAdd, CheckedSub, One, Zero};
+
+use crate::parsing::{parens, Parsable, TextParserResult};
+
+use super::{Point2D, Point3D};
+
+/// A point in `D`-dimensional space.
+/// Prefer [`Point2D`] and [`Point3D`] when the amount of dimensions is known to be two or three.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PointN<T, const D: usize>(pub [T; D]);
+
+impl<T: Copy, const D: usize> Copy for PointN<T, D> {}
+
+impl<T, const D: usize> PointN<T, D> {
+    /// The point located at the origin.
+    #[must_use]
+    pub fn zero() -> Self where T: Zero { Self(array::from_fn(|_| T::zero())) }
+
+    /// The point with every component set to one.
+    #[must_use]
+    pub fn one() -> Self where T: One { Self(array::from_fn(|_| T::one())) }
+
+    /// The distance between two points when using the
+    /// [manhattan (or taxicab) distance function](https://en.wikipedia.org/wiki/Taxicab_geometry).
+    pub fn manhattan_distance(self, other: Self) -> T where
+        T: Ord + Zero + Sub<Output=T>
+    {
+        self.component_distances(other)
+            .fold(T::zero(), |distance, component| distance + component)
+    }
+
+    /// The distance between two points when using the
+    /// [chebyshev distance function](https://en.wikipedia.org/wiki/Chebyshev_distance).
+    /// This is the amount of steps required to move between the points when diagonal steps are allowed.
+    pub fn chebyshev_distance(self, other: Self) -> T where
+        T: Ord + Zero + Sub<Output=T>
+    {
+        self.component_distances(other)
+            .max()
+            .unwrap_or_else(T::zero)
+    }
+
+    fn component_distances(self, Self(other): Self) -> impl Iterator<Item=T> where
+        T: Ord + Sub<Output=T>
+    {
+        self.0
+            .into_iter()
+            .zip(other)
+            .map(|(a, b)| {
+                let [min, max] = minmax(a, b);
+                max - min
+            })
+    }
+
+    /// Computes all `3^D - 1` neighbours of this point, including the diagonal ones.
+    /// If this calculation would overflow `T`, the neighbours are not included in the list.
+    /// ```
+    /// # use aoc_lib::geometry::PointN;
+    /// assert_eq!(80, PointN([0, 0, 0, 0]).neighbours().count());
+    /// assert_eq!(3, PointN([0u32, 0]).neighbours().count());
+    /// ```
+    pub fn neighbours(self) -> impl Iterator<Item=Self> where
+        T: Clone + One + CheckedAdd + CheckedSub
+    {
+        (0..D)
+            .map(|_| [-1, 0, 1])
+            .multi_cartesian_product()
+            .filter(|offset| offset.iter().any(|&delta| delta != 0))
+            .filter_map(move |offset| self.clone().offset_by(&offset))
+    }
+
+    /// Computes the `2 * D` neighbours of this point that differ in exactly one component.
+    /// If this calculation would overflow `T`, the neighbours are not included in the list.
+    pub fn orthogonal_neighbours(self) -> impl Iterator<Item=Self> where
+        T: Clone + One + CheckedAdd + CheckedSub
+    {
+        (0..D)
+            .cartesian_product([-1, 1])
+            .filter_map(move |(axis, delta)| {
+                let offset: [i8; D] = array::from_fn(|index| if index == axis { delta } else { 0 });
+                self.clone().offset_by(&offset)
+            })
+    }
+
+    fn offset_by(self, offset: &[i8]) -> Option<Self> where
+        T: One + CheckedAdd + CheckedSub
+    {
+        self.0
+            .into_iter()
+            .zip(offset)
+            .map(|(component, delta)| match delta {
+                -1 => component.checked_sub(&T::one()),
+                1 => component.checked_add(&T::one()),
+                _ => Some(component)
+            })
+            .collect::<Option<Vec<T>>>()?
+            .try_into()
+            .ok()
+            .map(Self)
+    }
+
+    /// Maps the individual components of the point.
+    pub fn map<U>(self, mapper: impl FnMut(T) -> U) -> PointN<U, D> {
+        PointN(self.0.map(mapper))
+    }
+
+    fn zip_with<U, V>(self, PointN(other): PointN<U, D>, mut f: impl FnMut(T, U) -> V) -> PointN<V, D> {
+        let mut components = self.0.into_iter().zip(other);
+        PointN(array::from_fn(|_| {
+            let (a, b) = components.next().unwrap();
+            f(a, b)
+        }))
+    }
+}
+
+impl<T: Add<Output=T>, const D: usize> Add for PointN<T, D> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, Add::add)
+    }
+}
+
+impl<T: Sub<Output=T>, const D: usize> Sub for PointN<T, D> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, Sub::sub)
+    }
+}
+
+impl<T: Mul<Output=T> + Clone, const D: usize> Mul<T> for PointN<T, D> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|component| component * rhs.clone())
+    }
+}
+
+impl<T: Neg<Output=T>, const D: usize> Neg for PointN<T, D> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(Neg::neg)
+    }
+}
+
+impl<T: AddAssign, const D: usize> AddAssign for PointN<T, D> {
+    fn add_assign(&mut self, Self(rhs): Self) {
+        for (component, delta) in self.0.iter_mut().zip(rhs) {
+            *component += delta;
+        }
+    }
+}
+
+impl<T: SubAssign, const D: usize> SubAssign for PointN<T, D> {
+    fn sub_assign(&mut self, Self(rhs): Self) {
+        for (component, delta) in self.0.iter_mut().zip(rhs) {
+            *component -= delta;
+        }
+    }
+}
+
+impl<T, const D: usize> Index<usize> for PointN<T, D> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.0[axis]
+    }
+}
+
+impl<T, const D: usize> IndexMut<usize> for PointN<T, D> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.0[axis]
+    }
+}
+
+impl<T: Default, const D: usize> Default for PointN<T, D> {
+    fn default() -> Self {
+        Self(array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const D: usize> From<[T; D]> for PointN<T, D> {
+    fn from(components: [T; D]) -> Self {
+        Self(components)
+    }
+}
+
+impl<T> From<Point2D<T>> for PointN<T, 2> {
+    fn from(Point2D(x, y): Point2D<T>) -> Self {
+        Self([x, y])
+    }
+}
+
+impl<T> From<PointN<T, 2>> for Point2D<T> {
+    fn from(PointN([x, y]): PointN<T, 2>) -> Self {
+        Self(x, y)
+    }
+}
+
+impl<T> From<Point3D<T>> for PointN<T, 3> {
+    fn from(Point3D(x, y, z): Point3D<T>) -> Self {
+        Self([x, y, z])
+    }
+}
+
+impl<T> From<PointN<T, 3>> for Point3D<T> {
+    fn from(PointN([x, y, z]): PointN<T, 3>) -> Self {
+        Self(x, y, z)
+    }
+}
+
+impl<T: Display, const D: usize> Display for PointN<T, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({})", self.0.iter().join(", "))
+    }
+}
+
+impl<'a, T: Parsable<'a>, const D: usize> Parsable<'a> for PointN<T, D> {
+    /// Parses a [`PointN`] from a string in the form ``a, b, c, ...`` or ``(a, b, c, ...)``,
+    /// containing exactly `D` components. The spaces are optional.
+    fn parse(input: &'a str) -> TextParserResult<'a, Self> {
+        let separator = || terminated(char(','), space0);
+        let point = || map_opt(
+            (T::parse, count(preceded(separator(), T::parse), D.saturating_sub(1))),
+            |(first, rest)| once(first)
+                .chain(rest)
+                .collect::<Vec<T>>()
+                .try_into()
+                .ok()
+                .map(Self)
+        );
+
+        parens(point()).or(point()).parse(input)
+    }
+}
\ No newline at end of file
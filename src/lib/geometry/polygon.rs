@@ -1,37 +1,88 @@
-use indexmap::IndexSet;
 use itertools::Itertools;
-use num::{Signed, Integer};
-use std::{hash::Hash, ops::{Add, Sub, Div, Mul}, fmt::Debug};
+use num::{Signed, Integer, Zero, One};
+use std::{cmp::minmax, ops::{Add, Sub, Mul, Neg}, fmt::Debug};
 
 use crate::iteration::ExtraIter;
 
-use super::{Point2D, Area};
+use super::{Point2D, Area, Transform2D, CardinalDirection, Directional};
 
-#[derive(Debug, Clone)]
+/// Where a point lies relative to a [`Polygon`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Containment {
+    Outside,
+    Boundary,
+    Inside
+}
+
+/// A polygon described by its vertices in order, where the last vertex connects back to the first.
+/// The same vertex may occur more than once, which allows for outlines that touch themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Polygon<T> {
-    points: IndexSet<Point2D<T>, ahash::RandomState>
+    vertices: Vec<Point2D<T>>
 }
 
 impl<T> Polygon<T> {
     #[must_use]
-    pub fn new() -> Self { Self { points: IndexSet::default() }}
+    pub const fn new() -> Self { Self { vertices: Vec::new() }}
 
-    /// Inserts a new point at the end of the polygon.
-    /// Returns `true` if the point was yet in the polygon's boundry, `false` otherwise.
-    pub fn push_point(&mut self, point: Point2D<T>) -> bool where
-        T: Hash + Eq
+    /// Traces the outline of a polygon by taking steps of the given length from the starting point.
+    /// Only the corners of the outline are stored as vertices,
+    /// consecutive steps in the same direction are merged and empty steps are skipped.
+    /// ```
+    /// # use aoc_lib::geometry::{CardinalDirection::*, Point2D, Polygon};
+    /// let steps = [(East, 2), (East, 4), (South, 3), (West, 6), (North, 3)];
+    /// let polygon = Polygon::from_steps(Point2D(0i64, 0), steps);
+    ///
+    /// assert_eq!(4, polygon.len());
+    /// assert_eq!(18, polygon.area());
+    /// assert_eq!(18, polygon.circumference());
+    /// assert_eq!(28, polygon.pick());
+    /// ```
+    pub fn from_steps(start: Point2D<T>, steps: impl IntoIterator<Item=(CardinalDirection, T)>) -> Self
+        where T: Copy + PartialEq + Zero + One + Neg<Output=T> + Mul<Output=T>
     {
-        self.points.insert(point)
+        let mut vertices = vec![start];
+        let mut previous = None;
+
+        for (direction, length) in steps {
+            if length.is_zero() { continue }
+
+            let position = *vertices.last().unwrap() + direction.direction_vector() * length;
+            if previous == Some(direction) { *vertices.last_mut().unwrap() = position; }
+            else { vertices.push(position); }
+
+            previous = Some(direction);
+        }
+
+        // The outline returns to the start
+        if vertices.len() > 1 && vertices.last() == vertices.first() { vertices.pop(); }
+
+        Self { vertices }
+    }
+
+    /// Inserts a new vertex at the end of the polygon.
+    pub fn push_point(&mut self, point: Point2D<T>) {
+        self.vertices.push(point);
     }
 
     #[must_use]
-    pub fn iter(&self) -> indexmap::set::Iter<'_, Point2D<T>> { self.into_iter() }
+    pub fn vertices(&self) -> &[Point2D<T>] { &self.vertices }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Point2D<T>> { self.into_iter() }
 
     #[must_use]
-    pub fn len(&self) -> usize { self.points.len() }
+    pub const fn len(&self) -> usize { self.vertices.len() }
 
     #[must_use]
-    pub fn is_empty(&self) -> bool { self.points.is_empty() }
+    pub const fn is_empty(&self) -> bool { self.vertices.is_empty() }
+
+    /// The edges of the polygon as pairs of consecutive vertices,
+    /// ending with the edge from the last vertex back to the first.
+    pub fn edges(&self) -> impl Iterator<Item=(Point2D<T>, Point2D<T>)> + '_
+        where T: Clone
+    {
+        self.vertices.iter().cloned().circular_tuple_windows()
+    }
 
     #[must_use]
     /// Constructs the bounding rectangle of the polygon.
@@ -45,31 +96,64 @@ impl<T> Polygon<T> {
     }
 
     #[must_use]
-    /// Computes the circumference of the shape using its boundry points.
+    /// Computes the circumference of the shape using the manhattan distance between its vertices,
+    /// for a polygon consisting of horizontal and vertical edges this is its perimeter.
     /// This operation is O(N)
     pub fn circumference(&self) -> T where
         T: Clone + Default + Add<Output=T> + Sub<Output=T> + Ord
     {
-        self.points.iter()
+        self.vertices.iter()
             .cloned()
             .circular_tuple_windows()
             .sum_by(|(a, b)| a.manhattan_distance(b))
     }
 
-    /// Computes the area of the polygon including the boundry using the [shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula).
+    /// Twice the area of the polygon, which is an integer for integer coordinates.
+    /// The coordinates are taken relative to the first vertex to keep the intermediate products small.
+    fn double_area(&self) -> T where
+        T: Clone + Signed
+    {
+        let Some(Point2D(origin, _)) = self.vertices.first().cloned() else { return T::zero() };
+
+        self.vertices.iter()
+            .cloned()
+            .circular_tuple_windows()
+            .fold(T::zero(), |area, (Point2D(x, y), Point2D(x2, y2))| {
+                area + (x - origin.clone() + x2 - origin.clone()) * (y2 - y)
+            })
+            .abs()
+    }
+
+    /// Computes the area enclosed by the vertices of the polygon using the [shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula).
     /// This operation is O(N).
     #[must_use]
     pub fn area(&self) -> T where
-        T: Add<Output=T> + Sub<Output=T> + Div<Output=T> + Mul<Output=T> +
-           Clone + Default + Signed + From<u8>
+        T: Clone + Signed
     {
-        let signed_area = self.points.iter()
+        self.double_area() / (T::one() + T::one())
+    }
+
+    /// The number of integer coordinates on the boundary of the polygon.
+    /// This operation is O(N).
+    #[must_use]
+    pub fn boundary_points(&self) -> T where
+        T: Clone + Signed + Integer
+    {
+        self.vertices.iter()
             .cloned()
             .circular_tuple_windows()
-            .sum_by(|(Point2D(x, y), Point2D(x2, y2))| (x * y2) - (x2 * y)) / T::from(2u8);
+            .fold(T::zero(), |points, (Point2D(x, y), Point2D(x2, y2))| points + (x2 - x).gcd(&(y2 - y)))
+    }
 
-        // For some reason this only works when it is called on an intermediate
-        signed_area.abs()
+    /// The number of integer coordinates strictly inside of the polygon
+    /// using [pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem).
+    /// This operation is O(N).
+    #[must_use]
+    pub fn interior_points(&self) -> T where
+        T: Clone + Signed + Integer
+    {
+        let two = T::one() + T::one();
+        (self.double_area() - self.boundary_points()) / two + T::one()
     }
 
     /// Computes the number of integer coordinates in the polygon including the boundry
@@ -77,18 +161,151 @@ impl<T> Polygon<T> {
     /// This operation is O(N).
     #[must_use]
     pub fn pick(&self) -> T where
-        T: Clone + Default + Signed + Integer + From<u8>
+        T: Clone + Signed + Integer
+    {
+        let two = T::one() + T::one();
+        (self.double_area() + self.boundary_points()) / two + T::one()
+    }
+
+    /// Finds where a point lies relative to the polygon by casting a ray from the point
+    /// and counting the edges it crosses, using the [even-odd rule](https://en.wikipedia.org/wiki/Even%E2%80%93odd_rule).
+    /// Points on an edge lie on the boundary, whichever side of the edge the polygon is on.
+    /// This operation is O(N).
+    /// ```
+    /// # use aoc_lib::geometry::{Containment, Point2D, Polygon};
+    /// let triangle: Polygon<i32> = [Point2D(0, 0), Point2D(6, 0), Point2D(0, 6)].into_iter().collect();
+    /// assert_eq!(Containment::Inside, triangle.locate(&Point2D(1, 2)));
+    /// assert_eq!(Containment::Boundary, triangle.locate(&Point2D(2, 4)));
+    /// assert_eq!(Containment::Outside, triangle.locate(&Point2D(4, 4)));
+    /// ```
+    #[must_use]
+    pub fn locate(&self, point: &Point2D<T>) -> Containment where
+        T: Clone + Signed + Ord
+    {
+        self.locate_scaled(point, &T::one())
+    }
+
+    /// Locates a point relative to the polygon with every vertex multiplied by `scale`.
+    /// This allows for locating points that lie in between the integer coordinates of the polygon.
+    fn locate_scaled(&self, Point2D(x, y): &Point2D<T>, scale: &T) -> Containment where
+        T: Clone + Signed + Ord
+    {
+        let mut inside = false;
+
+        for (Point2D(x1, y1), Point2D(x2, y2)) in self.edges() {
+            let (x1, y1) = (x1 * scale.clone(), y1 * scale.clone());
+            let (x2, y2) = (x2 * scale.clone(), y2 * scale.clone());
+
+            // Positive if the point lies to the left of the edge, when looking in the direction of increasing y
+            let cross = (x2.clone() - x1.clone()) * (y.clone() - y1.clone()) - (x.clone() - x1.clone()) * (y2.clone() - y1.clone());
+            let [min_x, max_x] = minmax(&x1, &x2);
+            let [min_y, max_y] = minmax(&y1, &y2);
+            if cross.is_zero() && (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y) {
+                return Containment::Boundary
+            }
+
+            // Count the edges that cross the horizontal ray to the right of the point
+            if (&y1 > y) != (&y2 > y) && cross.is_positive() == (y2 > y1) {
+                inside = !inside;
+            }
+        }
+
+        if inside { Containment::Inside } else { Containment::Outside }
+    }
+
+    /// Whether the point lies inside of the polygon or on its boundary.
+    /// This operation is O(N).
+    #[must_use]
+    pub fn contains(&self, point: &Point2D<T>) -> bool where
+        T: Clone + Signed + Ord
+    {
+        self.locate(point) != Containment::Outside
+    }
+
+    /// Whether the area lies entirely inside of the polygon, the boundary of the polygon counts as inside.
+    /// The polygon should only consist of horizontal and vertical edges.
+    ///
+    /// This operation is O(N) for areas with a non-zero width and height.
+    /// Areas with a zero width or height take O(N) for every vertex of the polygon that lies on them.
+    /// ```
+    /// # use aoc_lib::geometry::{Area, Point2D, Polygon};
+    /// // An L-shape
+    /// let polygon: Polygon<i32> = [(0, 0), (2, 0), (2, 2), (4, 2), (4, 4), (0, 4)]
+    ///     .into_iter()
+    ///     .map(Point2D::from)
+    ///     .collect();
+    ///
+    /// assert!(polygon.contains_area(&Area::from_corners(Point2D(0, 2), Point2D(4, 4))));
+    /// assert!(!polygon.contains_area(&Area::from_corners(Point2D(1, 1), Point2D(3, 3))));
+    /// assert!(polygon.contains_area(&Area::from_corners(Point2D(1, 0), Point2D(1, 4))));
+    /// assert!(!polygon.contains_area(&Area::from_corners(Point2D(1, 1), Point2D(3, 1))));
+    /// ```
+    #[must_use]
+    pub fn contains_area(&self, area: &Area<T>) -> bool where
+        T: Clone + Signed + Ord
+    {
+        let Area { top_left: Point2D(left, top), bottom_right: Point2D(right, bottom) } = area.clone();
+
+        // An edge cuts the area when it passes through the inside of the area, rather than along its sides
+        let cuts = |(Point2D(x1, y1), Point2D(x2, y2)): (Point2D<T>, Point2D<T>)| {
+            let [min_x, max_x] = minmax(x1.clone(), x2);
+            let [min_y, max_y] = minmax(y1.clone(), y2);
+
+            if min_x == max_x { left < x1 && x1 < right && min_y < bottom && top < max_y }
+            else { top < y1 && y1 < bottom && min_x < right && left < max_x }
+        };
+
+        if self.edges().any(cuts) { return false }
+
+        // The inside of the area does not touch the boundary of the polygon,
+        // so it lies either entirely inside or entirely outside of the polygon
+        let two = T::one() + T::one();
+        if left < right && top < bottom {
+            let center = Point2D(left + right, top + bottom);
+            return self.locate_scaled(&center, &two) == Containment::Inside
+        }
+
+        // The area is a line segment, which may run along the boundary of the polygon.
+        // In between the vertices on the segment it lies either inside, outside or along the boundary.
+        let horizontal = top == bottom;
+        let (start, end, line) = if horizontal { (left, right, top) } else { (top, bottom, left) };
+        let mut stops: Vec<T> = self.vertices.iter()
+            .filter(|vertex| area.contains(vertex))
+            .map(|Point2D(x, y)| if horizontal { x.clone() } else { y.clone() })
+            .chain([start, end])
+            .collect();
+
+        stops.sort_unstable();
+        stops.dedup();
+
+        let doubled = |position: T| if horizontal {
+            Point2D(position, line.clone() + line.clone())
+        } else {
+            Point2D(line.clone() + line.clone(), position)
+        };
+
+        stops.iter()
+            .map(|stop| stop.clone() + stop.clone())
+            .chain(stops.iter().tuple_windows().map(|(a, b)| a.clone() + b.clone()))
+            .all(|position| self.locate_scaled(&doubled(position), &two) != Containment::Outside)
+    }
+
+    /// Applies a transformation to every point of the polygon.
+    /// This operation is O(N).
+    #[must_use]
+    pub fn transform(&self, transform: &Transform2D<T>) -> Self where
+        T: Clone + Zero + Mul<Output=T>
     {
-        self.area() + self.circumference() / T::from(2u8) + T::from(1u8)
+        self.iter()
+            .map(|point| transform.apply(point.clone()))
+            .collect()
     }
 
     /// Flip the orientation of the polygon.
     /// If the points were in clockwise order, they will now be counter-clockwise.
     /// This operation is O(N).
-    pub fn flip(&mut self)
-        where T: Hash + Eq
-    {
-        self.points.reverse();
+    pub fn flip(&mut self) {
+        self.vertices.reverse();
     }
 }
 
@@ -98,32 +315,75 @@ impl<T> Default for Polygon<T> {
     }
 }
 
-impl<T: Hash + Eq> FromIterator<Point2D<T>> for Polygon<T> {
+impl<T> FromIterator<Point2D<T>> for Polygon<T> {
     fn from_iter<I: IntoIterator<Item = Point2D<T>>>(iter: I) -> Self {
-        Self { points: iter.into_iter().collect() }
+        Self { vertices: iter.into_iter().collect() }
     }
 }
 
-impl<T: Hash + Eq> Extend<Point2D<T>> for Polygon<T> {
+impl<T> Extend<Point2D<T>> for Polygon<T> {
     fn extend<I: IntoIterator<Item=Point2D<T>>>(&mut self, iter: I) {
-        self.points.extend(iter);
+        self.vertices.extend(iter);
     }
 }
 
-impl<T: Hash + Eq> IntoIterator for Polygon<T> {
+impl<T> IntoIterator for Polygon<T> {
     type Item = Point2D<T>;
-    type IntoIter = indexmap::set::IntoIter<Self::Item>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.points.into_iter()
+        self.vertices.into_iter()
     }
 }
 
 impl<'a, T> IntoIterator for &'a Polygon<T> {
     type Item = &'a Point2D<T>;
-    type IntoIter = indexmap::set::Iter<'a, Point2D<T>>;
+    type IntoIter = std::slice::Iter<'a, Point2D<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.points.iter()
+        self.vertices.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::{Area, CardinalDirection::*, Containment, Point2D, Polygon};
+
+    // Checks every integer and half-integer point, which covers every part of a rectilinear polygon
+    fn contains_area_brute_force(polygon: &Polygon<i32>, area: &Area<i32>) -> bool {
+        let doubled = Area::from_corners(area.top_left * 2, area.bottom_right * 2);
+        doubled.into_iter().all(|point| polygon.locate_scaled(&point, &2) != Containment::Outside)
+    }
+
+    #[test]
+    fn contains_area() {
+        let shapes = [
+            // A staircase with a notch
+            vec![(North, 4), (East, 2), (South, 2), (East, 1), (North, 2), (East, 2), (South, 2), (East, 1), (South, 2), (West, 6)],
+            // Two squares that touch at a corner
+            vec![(East, 2), (South, 2), (East, 2), (South, 2), (West, 2), (North, 2), (West, 2), (North, 2)]
+        ];
+
+        for steps in shapes {
+            let polygon = Polygon::from_steps(Point2D(0, 0), steps);
+            let corners = (-1..=6).cartesian_product(-5..=5).map(|(x, y)| Point2D(x, y)).collect_vec();
+
+            for (&a, &b) in corners.iter().tuple_combinations() {
+                let area = Area::from_corners(a, b);
+                assert_eq!(contains_area_brute_force(&polygon, &area), polygon.contains_area(&area), "{area}");
+            }
+        }
+    }
+
+    #[test]
+    fn locate_repeated_vertices() {
+        let polygon = Polygon::from_steps(Point2D(0, 0), [(East, 2), (South, 2), (East, 2), (South, 2), (West, 2), (North, 2), (West, 2), (North, 2)]);
+
+        assert_eq!(Containment::Boundary, polygon.locate(&Point2D(2, 2)));
+        assert_eq!(Containment::Inside, polygon.locate(&Point2D(3, 3)));
+        assert_eq!(Containment::Outside, polygon.locate(&Point2D(3, 1)));
+        assert_eq!(8, polygon.len());
     }
 }
\ No newline at end of file
@@ -0,0 +1,230 @@
+use std::{array, ops::{Mul, Neg}};
+
+use itertools::Itertools;
+use num::{One, Zero};
+
+use super::{Axis, Point2D, Point3D, RotationDirection};
+
+/// An affine transformation of 2D space built from quarter turns, reflections and translations.
+/// Transformations are composed using [`Transform2D::then`].
+/// ```
+/// # use aoc_lib::geometry::{Axis, Point2D, RotationDirection, Transform2D};
+/// let transform = Transform2D::rotation(RotationDirection::Right)
+///     .then(Transform2D::reflection(Axis::Vertical))
+///     .then(Transform2D::translation(Point2D(1, 1)));
+///
+/// assert_eq!(Point2D(2, 3), transform.apply(Point2D(2, 1)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Transform2D<T = i32> {
+    matrix: [[T; 2]; 2],
+    translation: Point2D<T>
+}
+
+impl<T: Copy> Copy for Transform2D<T> {}
+
+impl<T> Transform2D<T> {
+    /// The transformation that leaves every point in place.
+    #[must_use]
+    pub fn identity() -> Self where
+        T: Zero + One
+    {
+        Self::linear([
+            [T::one(), T::zero()],
+            [T::zero(), T::one()]
+        ])
+    }
+
+    /// Moves every point by `offset`.
+    #[must_use]
+    pub fn translation(offset: Point2D<T>) -> Self where
+        T: Zero + One
+    {
+        Self { translation: offset, ..Self::identity() }
+    }
+
+    /// A quarter turn around the origin.
+    /// Since the y-axis points south, turning right is clockwise when viewed on screen.
+    #[must_use]
+    pub fn rotation(direction: RotationDirection) -> Self where
+        T: Zero + One + Neg<Output=T>
+    {
+        Self::linear(match direction {
+            RotationDirection::Right => [
+                [T::zero(), -T::one()],
+                [T::one(), T::zero()]
+            ],
+            RotationDirection::Left => [
+                [T::zero(), T::one()],
+                [-T::one(), T::zero()]
+            ]
+        })
+    }
+
+    /// Mirrors every point across the given axis through the origin.
+    /// Reflecting across the horizontal axis flips the y-coordinate,
+    /// reflecting across the vertical axis flips the x-coordinate.
+    #[must_use]
+    pub fn reflection(axis: Axis) -> Self where
+        T: Zero + One + Neg<Output=T>
+    {
+        Self::linear(match axis {
+            Axis::Horizontal => [
+                [T::one(), T::zero()],
+                [T::zero(), -T::one()]
+            ],
+            Axis::Vertical => [
+                [-T::one(), T::zero()],
+                [T::zero(), T::one()]
+            ]
+        })
+    }
+
+    fn linear(matrix: [[T; 2]; 2]) -> Self where
+        T: Zero
+    {
+        Self { matrix, translation: Point2D(T::zero(), T::zero()) }
+    }
+
+    /// Applies the transformation to a point.
+    pub fn apply(&self, Point2D(x, y): Point2D<T>) -> Point2D<T> where
+        T: Clone + Zero + Mul<Output=T>
+    {
+        let [x, y] = multiply_vector(&self.matrix, &[x, y]);
+        Point2D(x, y) + self.translation.clone()
+    }
+
+    /// Chains two transformations, `next` is applied to the result of this transformation.
+    #[must_use]
+    pub fn then(self, next: Self) -> Self where
+        T: Clone + Zero + Mul<Output=T>
+    {
+        let Point2D(x, y) = self.translation;
+        let [x, y] = multiply_vector(&next.matrix, &[x, y]);
+
+        Self {
+            matrix: multiply(&next.matrix, &self.matrix),
+            translation: Point2D(x, y) + next.translation
+        }
+    }
+
+    /// Performs the transformation relative to `pivot` instead of the origin.
+    /// Rotations will turn around the pivot and reflections will mirror across the pivot.
+    #[must_use]
+    pub fn around(self, pivot: Point2D<T>) -> Self where
+        T: Clone + Zero + One + Neg<Output=T> + Mul<Output=T>
+    {
+        Self::translation(-pivot.clone())
+            .then(self)
+            .then(Self::translation(pivot))
+    }
+}
+
+/// An affine transformation of 3D space built from rotations and translations.
+/// Transformations are composed using [`Transform3D::then`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Transform3D<T = i32> {
+    matrix: [[T; 3]; 3],
+    translation: Point3D<T>
+}
+
+impl<T: Copy> Copy for Transform3D<T> {}
+
+impl<T> Transform3D<T> {
+    /// The transformation that leaves every point in place.
+    #[must_use]
+    pub fn identity() -> Self where
+        T: Zero + One
+    {
+        Self::linear([
+            [T::one(), T::zero(), T::zero()],
+            [T::zero(), T::one(), T::zero()],
+            [T::zero(), T::zero(), T::one()]
+        ])
+    }
+
+    /// Moves every point by `offset`.
+    #[must_use]
+    pub fn translation(offset: Point3D<T>) -> Self where
+        T: Zero + One
+    {
+        Self { translation: offset, ..Self::identity() }
+    }
+
+    /// Produces the 24 rotations that map the axes onto each other around the origin.
+    /// These are all the orientations a cube can be placed in, the first one is the identity.
+    pub fn rotations() -> impl Iterator<Item=Self> where
+        T: Zero + One + Neg<Output=T>
+    {
+        (0..3).permutations(3).flat_map(|axes| {
+            let inversions = axes
+                .iter()
+                .tuple_combinations()
+                .filter(|(a, b)| a > b)
+                .count();
+
+            (0..8u32)
+                .filter(move |signs| (signs.count_ones() as usize + inversions).is_multiple_of(2))
+                .map(move |signs| Self::linear([0, 1, 2].map(|row| {
+                    let value = || if signs & (1 << row) == 0 { T::one() } else { -T::one() };
+                    [0, 1, 2].map(|column| if axes[row] == column { value() } else { T::zero() })
+                })))
+        })
+    }
+
+    fn linear(matrix: [[T; 3]; 3]) -> Self where
+        T: Zero
+    {
+        Self { matrix, translation: Point3D(T::zero(), T::zero(), T::zero()) }
+    }
+
+    /// Applies the transformation to a point.
+    pub fn apply(&self, Point3D(x, y, z): Point3D<T>) -> Point3D<T> where
+        T: Clone + Zero + Mul<Output=T>
+    {
+        let [x, y, z] = multiply_vector(&self.matrix, &[x, y, z]);
+        Point3D(x, y, z) + self.translation.clone()
+    }
+
+    /// Chains two transformations, `next` is applied to the result of this transformation.
+    #[must_use]
+    pub fn then(self, next: Self) -> Self where
+        T: Clone + Zero + Mul<Output=T>
+    {
+        let Point3D(x, y, z) = self.translation;
+        let [x, y, z] = multiply_vector(&next.matrix, &[x, y, z]);
+
+        Self {
+            matrix: multiply(&next.matrix, &self.matrix),
+            translation: Point3D(x, y, z) + next.translation
+        }
+    }
+
+    /// Performs the transformation relative to `pivot` instead of the origin.
+    #[must_use]
+    pub fn around(self, pivot: Point3D<T>) -> Self where
+        T: Clone + Zero + One + Neg<Output=T> + Mul<Output=T>
+    {
+        Self::translation(-pivot.clone())
+            .then(self)
+            .then(Self::translation(pivot))
+    }
+}
+
+fn multiply<T, const N: usize>(lhs: &[[T; N]; N], rhs: &[[T; N]; N]) -> [[T; N]; N] where
+    T: Clone + Zero + Mul<Output=T>
+{
+    array::from_fn(|row| array::from_fn(|column| {
+        (0..N).fold(T::zero(), |sum, index| sum + lhs[row][index].clone() * rhs[index][column].clone())
+    }))
+}
+
+fn multiply_vector<T, const N: usize>(matrix: &[[T; N]; N], vector: &[T; N]) -> [T; N] where
+    T: Clone + Zero + Mul<Output=T>
+{
+    matrix.clone().map(|row| row
+        .into_iter()
+        .zip(vector.iter().cloned())
+        .fold(T::zero(), |sum, (a, b)| sum + a * b)
+    )
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+use std::hash::Hash;
+
+use ahash::RandomState;
+use indexmap::IndexSet;
+
+/// The states produced by repeatedly applying a function to a seed,
+/// up until the first state that was already produced before.
+/// ```
+/// # use aoc_lib::iteration::Cycle;
+/// let cycle = Cycle::find(1, |&n| n * 3 % 10);
+/// assert_eq!(0, cycle.start);
+/// assert_eq!(4, cycle.length);
+/// assert_eq!(&7, cycle.nth(1_000_000_003));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+    /// The index of the first state that is part of the cycle.
+    pub start: usize,
+    /// The amount of states in the cycle.
+    pub length: usize,
+    states: IndexSet<S, RandomState>
+}
+
+impl<S: Hash + Eq> Cycle<S> {
+    pub fn find(seed: S, mut step: impl FnMut(&S) -> S) -> Self {
+        let mut states = IndexSet::with_hasher(RandomState::new());
+        let mut state = seed;
+
+        let start = loop {
+            if let Some(index) = states.get_index_of(&state) { break index }
+
+            let next = step(&state);
+            states.insert(state);
+            state = next;
+        };
+
+        Self { start, length: states.len() - start, states }
+    }
+
+    /// The state after applying the function `n` times.
+    #[must_use]
+    pub fn nth(&self, n: usize) -> &S {
+        let index = if n < self.states.len() { n }
+            else { self.start + (n - self.start) % self.length };
+
+        &self.states[index]
+    }
+
+    /// The states in the order they were produced, the cycle makes up the last states.
+    pub fn states(&self) -> impl Iterator<Item=&S> {
+        self.states.iter()
+    }
+}
\ No newline at end of file
@@ -4,11 +4,13 @@ mod mode;
 mod try_fold_while;
 mod generator;
 mod transpose;
+mod cycle;
 pub mod queue;
 
 pub use generator::{Generator, generate};
 pub use try_fold_while::TryFoldWhile;
 pub use transpose::Transpose;
+pub use cycle::Cycle;
 
 pub trait ExtraIter: Iterator + Sized {
     /// Returns true if the iterator contains no elements.
@@ -0,0 +1,56 @@
+use num::{Integer, Zero, rational::Ratio};
+
+/// Solves a system of linear equations exactly using [Gauss-Jordan elimination](https://en.wikipedia.org/wiki/Gaussian_elimination).
+///
+/// Every equation consists of the coefficients of the unknowns followed by the constant term.
+/// Returns `None` if the system does not have exactly one solution.
+/// ```
+/// # use aoc_lib::math::solve_linear_system;
+/// # use num::rational::Ratio;
+/// // x + y = 3
+/// // 2x - y = 0
+/// let solution = solve_linear_system([[1, 1, 3], [2, -1, 0]]);
+/// assert_eq!(Some(vec![Ratio::from_integer(1), Ratio::from_integer(2)]), solution);
+/// ```
+pub fn solve_linear_system<T, E, C>(equations: E) -> Option<Vec<Ratio<T>>> where
+    E: IntoIterator<Item: IntoIterator<Item=C>>,
+    C: Into<Ratio<T>>,
+    T: Integer + Clone
+{
+    let mut matrix: Vec<Vec<Ratio<T>>> = equations
+        .into_iter()
+        .map(|equation| equation.into_iter().map(Into::into).collect())
+        .collect();
+
+    let unknowns = matrix.len();
+    if matrix.iter().any(|equation| equation.len() != unknowns + 1) { return None }
+
+    for column in 0..unknowns {
+        let pivot = (column..unknowns).find(|&row| !matrix[row][column].is_zero())?;
+        matrix.swap(column, pivot);
+
+        let pivot = matrix[column][column].clone();
+        let pivot_row: Vec<Ratio<T>> = matrix[column]
+            .iter()
+            .map(|value| value.clone() / pivot.clone())
+            .collect();
+
+        for (row, equation) in matrix.iter_mut().enumerate() {
+            if row == column { continue }
+
+            let factor = equation[column].clone();
+            if factor.is_zero() { continue }
+
+            for (value, pivot_value) in equation.iter_mut().zip(&pivot_row) {
+                *value = value.clone() - factor.clone() * pivot_value.clone();
+            }
+        }
+
+        matrix[column] = pivot_row;
+    }
+
+    matrix
+        .into_iter()
+        .map(|mut equation| equation.pop())
+        .collect()
+}
\ No newline at end of file
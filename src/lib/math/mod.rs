@@ -1,7 +1,15 @@
 mod bit;
 mod range;
+mod range_set;
 mod quadratic;
+mod polynomial;
+mod linear_system;
+mod number_theory;
 
 pub use bit::*;
 pub use range::*;
-pub use quadratic::*;
\ No newline at end of file
+pub use range_set::*;
+pub use quadratic::*;
+pub use polynomial::*;
+pub use linear_system::*;
+pub use number_theory::*;
\ No newline at end of file
@@ -0,0 +1,362 @@
+use num::{Integer, Signed};
+
+/// Computes the greatest common divisor of `a` and `b`,
+/// along with coefficients `x` and `y` such that `a * x + b * y = gcd`.
+/// ```
+/// # use aoc_lib::math::extended_gcd;
+/// let (gcd, x, y) = extended_gcd(240, 46);
+/// assert_eq!(2, gcd);
+/// assert_eq!(gcd, 240 * x + 46 * y);
+/// ```
+#[must_use]
+pub fn extended_gcd<T>(a: T, b: T) -> (T, T, T)
+    where T: Integer + Signed + Copy
+{
+    let (mut old_remainder, mut remainder) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while !remainder.is_zero() {
+        let quotient = old_remainder / remainder;
+        (old_remainder, remainder) = (remainder, old_remainder - quotient * remainder);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_remainder.is_negative() { (-old_remainder, -old_x, -old_y) }
+    else { (old_remainder, old_x, old_y) }
+}
+
+/// Finds `x` in `0..modulus` such that `value * x ≡ 1 (mod modulus)`.
+/// Returns `None` if `value` and `modulus` are not coprime.
+#[must_use]
+pub fn mod_inverse<T>(value: T, modulus: T) -> Option<T>
+    where T: Integer + Signed + Copy
+{
+    let (gcd, x, _) = extended_gcd(value, modulus);
+    gcd.is_one().then(|| x.mod_floor(&modulus))
+}
+
+/// Computes `base ^ exponent mod modulus` by repeated squaring.
+/// The square of the modulus has to fit in `T`.
+/// ```
+/// # use aoc_lib::math::mod_pow;
+/// assert_eq!(445, mod_pow(4u64, 13, 497));
+/// ```
+#[must_use]
+pub fn mod_pow<T>(base: T, mut exponent: T, modulus: T) -> T
+    where T: Integer + Copy
+{
+    let two = T::one() + T::one();
+    let mut base = base.mod_floor(&modulus);
+    let mut result = T::one().mod_floor(&modulus);
+
+    while exponent > T::zero() {
+        if exponent.is_odd() { result = result * base % modulus; }
+        base = base * base % modulus;
+        exponent = exponent / two;
+    }
+
+    result
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)` using the
+/// [Chinese remainder theorem](https://en.wikipedia.org/wiki/Chinese_remainder_theorem).
+///
+/// The moduli don't have to be coprime.
+/// Returns the smallest non-negative solution together with the least common multiple of the moduli,
+/// every other solution differs from it by a multiple of that.
+/// Returns `None` if the congruences contradict each other.
+/// ```
+/// # use aoc_lib::math::chinese_remainder;
+/// assert_eq!(Some((23, 105)), chinese_remainder([(2, 3), (3, 5), (2, 7)]));
+/// assert_eq!(Some((7, 12)), chinese_remainder([(1, 6), (3, 4)]));
+/// assert_eq!(None, chinese_remainder([(0, 6), (1, 4)]));
+/// ```
+#[must_use]
+pub fn chinese_remainder<T>(congruences: impl IntoIterator<Item=(T, T)>) -> Option<(T, T)>
+    where T: Integer + Signed + Copy
+{
+    congruences.into_iter().try_fold((T::zero(), T::one()), |(residue, modulus), (other_residue, other_modulus)| {
+        let (gcd, x, _) = extended_gcd(modulus, other_modulus);
+        let (quotient, remainder) = (other_residue - residue).div_rem(&gcd);
+        if !remainder.is_zero() { return None }
+
+        let step = other_modulus / gcd;
+        let lcm = modulus * step;
+        let residue = residue + modulus * (quotient * x).mod_floor(&step);
+        Some((residue.mod_floor(&lcm), lcm))
+    })
+}
+
+/// The prime factorization of a positive integer,
+/// as pairs of distinct primes and their exponents in ascending order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Factorization<T> {
+    factors: Vec<(T, u32)>
+}
+
+impl<T: Integer + Copy> Factorization<T> {
+    /// The distinct prime factors and their exponents, in ascending order.
+    #[must_use]
+    pub fn factors(&self) -> &[(T, u32)] { &self.factors }
+
+    /// The number that was factorized.
+    #[must_use]
+    pub fn value(&self) -> T {
+        self.factors
+            .iter()
+            .fold(T::one(), |value, &(prime, exponent)| value * num::pow(prime, exponent as usize))
+    }
+
+    #[must_use]
+    pub fn is_prime(&self) -> bool {
+        matches!(self.factors.as_slice(), [(_, 1)])
+    }
+
+    /// The amount of positive divisors of the number.
+    #[must_use]
+    pub fn divisor_count(&self) -> usize {
+        self.factors
+            .iter()
+            .map(|&(_, exponent)| exponent as usize + 1)
+            .product()
+    }
+
+    /// The positive divisors of the number in ascending order, including 1 and the number itself.
+    /// ```
+    /// # use aoc_lib::math::factorize;
+    /// assert_eq!(vec![1, 2, 3, 4, 6, 12], factorize(12).divisors());
+    /// ```
+    #[must_use]
+    pub fn divisors(&self) -> Vec<T> {
+        let mut divisors = vec![T::one()];
+
+        for &(prime, exponent) in &self.factors {
+            let smaller = divisors.len();
+            let mut power = T::one();
+            for _ in 0..exponent {
+                power = power * prime;
+                for index in 0..smaller { divisors.push(divisors[index] * power); }
+            }
+        }
+
+        divisors.sort_unstable();
+        divisors
+    }
+
+    /// The sum of the positive divisors of the number, including 1 and the number itself.
+    /// ```
+    /// # use aoc_lib::math::factorize;
+    /// assert_eq!(28, factorize(12).divisor_sum());
+    /// ```
+    #[must_use]
+    pub fn divisor_sum(&self) -> T {
+        self.factors
+            .iter()
+            .map(|&(prime, exponent)| {
+                (0..exponent).fold((T::one(), T::one()), |(sum, power), _| {
+                    let power = power * prime;
+                    (sum + power, power)
+                }).0
+            })
+            .fold(T::one(), |product, sum| product * sum)
+    }
+}
+
+impl<T> IntoIterator for Factorization<T> {
+    type Item = (T, u32);
+    type IntoIter = std::vec::IntoIter<(T, u32)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.factors.into_iter()
+    }
+}
+
+/// Factorizes a number using trial division.
+///
+/// # Panics
+/// If the number is not positive.
+/// ```
+/// # use aoc_lib::math::factorize;
+/// assert_eq!(&[(2, 2), (3, 1), (7, 1)], factorize(84).factors());
+/// ```
+#[must_use]
+pub fn factorize<T>(mut n: T) -> Factorization<T>
+    where T: Integer + Copy
+{
+    assert!(n > T::zero(), "Only positive numbers can be factorized");
+
+    let mut factors = Vec::new();
+    let mut divisor = T::one() + T::one();
+
+    while divisor <= n / divisor {
+        let mut exponent = 0;
+        while n.is_multiple_of(&divisor) {
+            n = n / divisor;
+            exponent += 1;
+        }
+
+        if exponent > 0 { factors.push((divisor, exponent)); }
+        divisor = divisor + T::one();
+    }
+
+    if !n.is_one() { factors.push((n, 1)); }
+    Factorization { factors }
+}
+
+/// The smallest prime factor of every number below a limit,
+/// computed using the [sieve of Eratosthenes](https://en.wikipedia.org/wiki/Sieve_of_Eratosthenes).
+///
+/// This makes it cheap to test for primality and to factorize any number below the limit.
+/// ```
+/// # use aoc_lib::math::Sieve;
+/// let sieve = Sieve::new(30);
+/// assert_eq!(vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29], sieve.primes().collect::<Vec<_>>());
+/// assert!(sieve.is_prime(13));
+/// assert_eq!(Some(&[(2, 3), (3, 1)][..]), sieve.factorize(24).as_ref().map(|factors| factors.factors()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sieve {
+    smallest_factors: Vec<usize>
+}
+
+impl Sieve {
+    #[must_use]
+    pub fn new(limit: usize) -> Self {
+        let mut smallest_factors = vec![0; limit];
+
+        for n in 2..limit {
+            if smallest_factors[n] != 0 { continue }
+
+            smallest_factors[n] = n;
+            for multiple in (n.saturating_mul(n)..limit).step_by(n) {
+                if smallest_factors[multiple] == 0 { smallest_factors[multiple] = n; }
+            }
+        }
+
+        Self { smallest_factors }
+    }
+
+    /// The numbers covered by the sieve are those below the limit.
+    #[must_use]
+    pub const fn limit(&self) -> usize { self.smallest_factors.len() }
+
+    /// The smallest prime factor of the number,
+    /// `None` if the number is 0, 1 or not below the limit.
+    #[must_use]
+    pub fn smallest_factor(&self, n: usize) -> Option<usize> {
+        self.smallest_factors.get(n).copied().filter(|&factor| factor != 0)
+    }
+
+    #[must_use]
+    pub fn is_prime(&self, n: usize) -> bool {
+        self.smallest_factor(n) == Some(n)
+    }
+
+    /// The primes below the limit in ascending order.
+    pub fn primes(&self) -> impl Iterator<Item=usize> + '_ {
+        self.smallest_factors
+            .iter()
+            .enumerate()
+            .skip(2)
+            .filter_map(|(n, &factor)| (factor == n).then_some(n))
+    }
+
+    /// Factorizes a positive number below the limit.
+    #[must_use]
+    pub fn factorize(&self, mut n: usize) -> Option<Factorization<usize>> {
+        if n == 0 || n >= self.limit() { return None }
+
+        let mut factors: Vec<(usize, u32)> = Vec::new();
+        while let Some(prime) = self.smallest_factor(n) {
+            match factors.last_mut() {
+                Some((last, exponent)) if *last == prime => *exponent += 1,
+                _ => factors.push((prime, 1))
+            }
+
+            n /= prime;
+        }
+
+        Some(Factorization { factors })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{chinese_remainder, extended_gcd, factorize, mod_inverse, mod_pow, Sieve};
+
+    #[test]
+    fn extended_gcd_coefficients() {
+        for (a, b) in [(240, 46), (-12, 18), (7, 0), (0, 5), (17, -5)] {
+            let (gcd, x, y) = extended_gcd(a, b);
+            assert_eq!(num::integer::gcd(a, b), gcd);
+            assert_eq!(gcd, a * x + b * y);
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(5), mod_inverse(-10, 17));
+        assert_eq!(None, mod_inverse(6, 9));
+    }
+
+    #[test]
+    fn power() {
+        assert_eq!(1, mod_pow(7, 0, 13));
+        assert_eq!(0, mod_pow(7, 5, 1));
+        assert_eq!(31_349_995, mod_pow(252_533u64, 17_850, 33_554_393));
+    }
+
+    #[test]
+    fn chinese_remainder_coprime() {
+        assert_eq!(Some((5, 35)), chinese_remainder([(0, 5), (5, 7)]));
+        assert_eq!(Some((39, 60)), chinese_remainder([(-1, 4), (0, 3), (4, 5)]));
+    }
+
+    #[test]
+    fn chinese_remainder_non_coprime() {
+        assert_eq!(Some((10, 30)), chinese_remainder([(4, 6), (10, 15)]));
+        assert_eq!(None, chinese_remainder([(4, 6), (9, 15)]));
+        assert_eq!(Some((0, 1)), chinese_remainder(std::iter::empty::<(i64, i64)>()));
+    }
+
+    #[test]
+    fn factorization() {
+        assert!(factorize(1).factors().is_empty());
+        assert_eq!(&[(97, 1)], factorize(97).factors());
+        assert_eq!(&[(2, 4), (3, 2), (5, 1)], factorize(720).factors());
+        assert_eq!(720, factorize(720).value());
+        assert_eq!(30, factorize(720).divisor_count());
+        assert_eq!(2418, factorize(720).divisor_sum());
+    }
+
+    #[test]
+    fn factorize_near_max() {
+        assert_eq!(&[(251u8, 1)], factorize(251u8).factors());
+        assert_eq!(&[(3u8, 1), (5, 1), (17, 1)], factorize(u8::MAX).factors());
+        assert_eq!(&[(4_294_967_291u32, 1)], factorize(4_294_967_291u32).factors());
+    }
+
+    #[test]
+    fn divisors() {
+        let divisors = factorize(360).divisors();
+        assert_eq!(24, divisors.len());
+        assert!(divisors.is_sorted());
+        assert!(divisors.iter().all(|divisor| 360 % divisor == 0));
+        assert_eq!(divisors.iter().sum::<i32>(), factorize(360).divisor_sum());
+    }
+
+    #[test]
+    fn sieve() {
+        let sieve = Sieve::new(1000);
+        assert_eq!(168, sieve.primes().count());
+        assert!(!sieve.is_prime(0) && !sieve.is_prime(1) && sieve.is_prime(997));
+        assert_eq!(None, sieve.factorize(1000));
+
+        for n in 1..1000 {
+            assert_eq!(Some(factorize(n)), sieve.factorize(n));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,167 @@
+use std::ops::Mul;
+
+use num::{Integer, Zero, rational::Ratio};
+
+/// A polynomial in a single variable, stored as its coefficients in ascending order of degree.
+/// ```
+/// # use aoc_lib::math::Polynomial;
+/// // 3x² - 2x + 1
+/// let polynomial = Polynomial::new([1, -2, 3]);
+/// assert_eq!(Some(2), polynomial.degree());
+/// assert_eq!(9, polynomial.evaluate(&2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polynomial<T = f64> {
+    coefficients: Vec<T>
+}
+
+impl<T> Polynomial<T> {
+    /// Creates a polynomial from its coefficients, starting with the constant term.
+    pub fn new(coefficients: impl IntoIterator<Item=T>) -> Self
+        where T: Zero
+    {
+        let mut coefficients: Vec<T> = coefficients.into_iter().collect();
+        while coefficients.last().is_some_and(Zero::is_zero) { coefficients.pop(); }
+        Self { coefficients }
+    }
+
+    /// The coefficients in ascending order of degree, without trailing zeroes.
+    #[must_use]
+    pub fn coefficients(&self) -> &[T] { &self.coefficients }
+
+    /// The degree of the polynomial, `None` if every coefficient is zero.
+    #[must_use]
+    pub const fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn evaluate(&self, x: &T) -> T
+        where T: Clone + Zero + Mul<Output=T>
+    {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(T::zero(), |value, coefficient| value * x.clone() + coefficient.clone())
+    }
+}
+
+impl<T: Integer + Clone> Polynomial<Ratio<T>> {
+    /// Finds the polynomial of the lowest degree passing through all of the points using
+    /// [Lagrange interpolation](https://en.wikipedia.org/wiki/Lagrange_polynomial).
+    /// The coefficients are computed exactly as fractions.
+    ///
+    /// Returns `None` if two points share an x coordinate.
+    /// ```
+    /// # use aoc_lib::math::Polynomial;
+    /// # use num::rational::Ratio;
+    /// let polynomial = Polynomial::interpolate([(1, 3), (2, 9), (3, 19)]).unwrap();
+    /// assert_eq!(Polynomial::new([1, 0, 2].map(Ratio::from_integer)), polynomial);
+    /// ```
+    pub fn interpolate(points: impl IntoIterator<Item=(T, T)>) -> Option<Self> {
+        let points: Vec<(Ratio<T>, Ratio<T>)> = points
+            .into_iter()
+            .map(|(x, y)| (Ratio::from_integer(x), Ratio::from_integer(y)))
+            .collect();
+
+        let mut coefficients = vec![Ratio::zero(); points.len()];
+        for (i, (x, y)) in points.iter().enumerate() {
+            let mut basis = vec![Ratio::from_integer(T::one())];
+            let mut denominator = Ratio::from_integer(T::one());
+
+            for (_, (other, _)) in points.iter().enumerate().filter(|&(j, _)| j != i) {
+                if other == x { return None }
+
+                // Multiply the basis polynomial by (x - other)
+                basis.insert(0, Ratio::zero());
+                for degree in 0..basis.len() - 1 {
+                    let shifted = basis[degree + 1].clone() * other.clone();
+                    basis[degree] = basis[degree].clone() - shifted;
+                }
+
+                denominator = denominator * (x.clone() - other.clone());
+            }
+
+            let scale = y.clone() / denominator;
+            for (coefficient, term) in coefficients.iter_mut().zip(basis) {
+                *coefficient = coefficient.clone() + term * scale.clone();
+            }
+        }
+
+        Some(Self::new(coefficients))
+    }
+}
+
+/// Evaluates the polynomial of the lowest degree passing through samples taken at `0, 1, 2, ...`
+/// using [Newton's forward differences](https://en.wikipedia.org/wiki/Newton_polynomial).
+///
+/// The computation is exact and never leaves the integers, `x` can lie outside of the sampled values.
+/// ```
+/// # use aoc_lib::math::extrapolate;
+/// // x² + x + 1
+/// assert_eq!(111, extrapolate(&[1, 3, 7], 10));
+/// assert_eq!(1, extrapolate(&[1, 3, 7], -1));
+/// ```
+#[must_use]
+pub fn extrapolate<T>(samples: &[T], x: T) -> T
+    where T: Integer + Copy
+{
+    let mut differences = samples.to_vec();
+    let mut value = T::zero();
+    let mut binomial = T::one();
+    let mut k = T::zero();
+
+    while let Some(first) = differences.first() {
+        // Accumulate binomial(x, k) * Δᵏ
+        value = value + binomial * *first;
+        binomial = binomial * (x - k);
+        k = k + T::one();
+        binomial = binomial / k;
+
+        differences = differences
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect();
+    }
+
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use num::rational::Ratio;
+
+    use crate::math::{extrapolate, Polynomial};
+
+    #[test]
+    fn trims_trailing_zeroes() {
+        assert_eq!(&[1, 2], Polynomial::new([1, 2, 0, 0]).coefficients());
+        assert_eq!(None, Polynomial::new([0, 0]).degree());
+        assert_eq!(0, Polynomial::new([0]).evaluate(&5));
+    }
+
+    #[test]
+    fn interpolation() {
+        let points = [(-2, 15), (0, -1), (1, 3), (3, -5)];
+        let polynomial = Polynomial::interpolate(points).unwrap();
+
+        assert_eq!(Some(3), polynomial.degree());
+        for (x, y) in points {
+            assert_eq!(Ratio::from_integer(y), polynomial.evaluate(&Ratio::from_integer(x)));
+        }
+
+        assert_eq!(None, Polynomial::interpolate([(1, 2), (1, 3)]));
+    }
+
+    #[test]
+    fn extrapolation() {
+        let cubic = |x: i64| 2 * x * x * x - x + 7;
+        let samples = (0..4).map(cubic).collect::<Vec<_>>();
+
+        for x in -5..20 {
+            assert_eq!(cubic(x), extrapolate(&samples, x));
+        }
+
+        assert_eq!(0, extrapolate::<i64>(&[], 3));
+        assert_eq!(28, extrapolate(&[10, 13, 16, 19, 22, 25], 6));
+    }
+}
\ No newline at end of file
@@ -1,11 +1,18 @@
-use num::traits::real::Real;
+use std::ops::{Add, Mul};
 
+use num::{integer::Roots as IntegerRoots, traits::real::Real, Integer, Signed};
+
+use super::{Inclusive, Range};
+
+/// The function `a * x² + b * x + c`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Quadratic<T = f64> {
     pub a: T,
     pub b: T,
     pub c: T,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Roots<T> {
     None,
     Single(T),
@@ -13,19 +20,101 @@ pub enum Roots<T> {
 }
 
 impl<T> Quadratic<T> {
-    pub fn roots(self) -> Roots<T> where T: Real + From<u8> {
+    pub fn evaluate(&self, x: T) -> T
+        where T: Copy + Add<Output=T> + Mul<Output=T>
+    {
+        (self.a * x + self.b) * x + self.c
+    }
+
+    /// The real roots of the function, a pair of roots is in ascending order.
+    /// If `a` is zero the function is linear and has a single root, unless it is constant.
+    /// ```
+    /// # use aoc_lib::math::{Quadratic, Roots};
+    /// assert_eq!(Roots::Pair(-2.0, 3.0), Quadratic { a: -1.0, b: 1.0, c: 6.0 }.roots());
+    /// assert_eq!(Roots::Single(1.0), Quadratic { a: 1.0, b: -2.0, c: 1.0 }.roots());
+    /// assert_eq!(Roots::None, Quadratic { a: 1.0, b: 0.0, c: 1.0 }.roots());
+    /// ```
+    pub fn roots(self) -> Roots<T> where T: Real {
         let Self { a, b, c } = self;
+        let two = T::one() + T::one();
 
-        let discriminant = b * b - <T as From<u8>>::from(4) * a * c;
-        if discriminant.is_zero() { return Roots::None }
+        if a.is_zero() {
+            return if b.is_zero() { Roots::None } else { Roots::Single(-c / b) }
+        }
 
-        let root = discriminant.sqrt();
-        let denominator = <T as From<u8>>::from(2) * a;
+        let discriminant = b * b - two * two * a * c;
+        if discriminant < T::zero() { return Roots::None }
 
-        let first = (-b - root) / denominator;        
-        if discriminant.is_sign_negative() { return Roots::Single(first) }
+        let denominator = two * a;
+        if discriminant.is_zero() { return Roots::Single(-b / denominator) }
 
+        let root = discriminant.sqrt();
+        let first = (-b - root) / denominator;
         let second = (-b + root) / denominator;
-        Roots::Pair(first, second)
+        Roots::Pair(first.min(second), first.max(second))
+    }
+}
+
+impl<T: Integer + Signed + IntegerRoots + Copy> Quadratic<T> {
+    /// The integers that lie strictly between the real roots of the function,
+    /// these are the values where the sign of the function is opposite to the sign of `a`.
+    /// This is computed exactly, without resorting to floating point arithmetic.
+    ///
+    /// Returns `None` if no integer lies between the roots, or if `a` is zero.
+    /// ```
+    /// # use aoc_lib::{math::Quadratic, range};
+    /// // x * (7 - x) > 9
+    /// let race = Quadratic { a: -1, b: 7, c: -9 };
+    /// assert_eq!(Some(range!(2..=5)), race.between_roots());
+    /// ```
+    #[must_use]
+    pub fn between_roots(&self) -> Option<Range<T, Inclusive>> {
+        // Make the function negative between the roots
+        let quadratic = if self.a.is_negative() { Self { a: -self.a, b: -self.b, c: -self.c } } else { *self };
+        let Self { a, b, c } = quadratic;
+        if a.is_zero() { return None }
+
+        let two = T::one() + T::one();
+        let discriminant = b * b - two * two * a * c;
+        if !discriminant.is_positive() { return None }
+
+        // The integer square root puts the estimates within one of the actual bounds
+        let root = discriminant.sqrt();
+        let mut start = (-b - root).div_floor(&(two * a));
+        let mut end = (-b + root).div_floor(&(two * a)) + T::one();
+        let inside = |x: T| quadratic.evaluate(x).is_negative();
+
+        while start <= end && !inside(start) { start = start + T::one(); }
+        while start <= end && !inside(end) { end = end - T::one(); }
+        if start > end { return None }
+
+        while inside(start - T::one()) { start = start - T::one(); }
+        while inside(end + T::one()) { end = end + T::one(); }
+
+        Some(Range::new_unchecked(start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{math::{Quadratic, Roots}, range};
+
+    #[test]
+    fn roots() {
+        assert_eq!(Roots::Pair(1.0, 2.0), Quadratic { a: 1.0, b: -3.0, c: 2.0 }.roots());
+        assert_eq!(Roots::Single(-1.0), Quadratic { a: 2.0, b: 4.0, c: 2.0 }.roots());
+        assert_eq!(Roots::None, Quadratic { a: -1.0, b: 0.0, c: -4.0 }.roots());
+        assert_eq!(Roots::Single(-2.0), Quadratic { a: 0.0, b: 3.0, c: 6.0 }.roots());
+        assert_eq!(Roots::None, Quadratic { a: 0.0, b: 0.0, c: 6.0 }.roots());
+    }
+
+    #[test]
+    fn between_roots() {
+        assert_eq!(Some(range!(11..=19)), Quadratic { a: -1, b: 30, c: -200 }.between_roots());
+        assert_eq!(Some(range!(11..=19)), Quadratic { a: 1, b: -30, c: 200 }.between_roots());
+        assert_eq!(Some(range!(-2..=2)), Quadratic { a: 1, b: 0, c: -5 }.between_roots());
+        assert_eq!(None, Quadratic { a: 1, b: -2, c: 1 }.between_roots());
+        assert_eq!(None, Quadratic { a: 4, b: -2, c: 0 }.between_roots());
+        assert_eq!(None, Quadratic { a: 0, b: 1, c: 1 }.between_roots());
     }
 }
\ No newline at end of file
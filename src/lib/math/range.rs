@@ -1,30 +1,73 @@
 // We have `std::iter::range`, What is the purpose of this?
 // https://kaylynn.gay/blog/post/rust_ranges_and_suffering
 
-use std::{cmp::{Ordering, max, min}, fmt::Display, iter::Step, ops::{Add, Div, Sub}};
+use std::{cmp::{Ordering, max, min}, fmt::{Debug, Display}, hash::Hash, iter::Step, marker::PhantomData, ops::{self, Add, Div, RangeBounds, RangeInclusive, Sub}};
 
-use num::{FromPrimitive, One, Zero};
+use num::{CheckedAdd, CheckedSub, FromPrimitive, One, Zero};
 use thiserror::Error;
 
 #[derive(Debug, Error, PartialEq, Eq, Hash)]
 #[error("Invalid range, start can't be greater than end: {0}")]
-pub struct InvalidRangeError<T>(pub Range<T>);
+pub struct InvalidRangeError<T, B: BoundKind = Exclusive>(pub Range<T, B>);
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Whether the upper bound of a [`Range`] is part of the range.
+pub trait BoundKind: sealed::Sealed + Debug + Clone + Copy + PartialEq + Eq + Hash {
+    const INCLUSIVE: bool;
+}
+
+/// The upper bound is not part of the range, like `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Exclusive;
+
+/// The upper bound is part of the range, like `start..=end`.
+/// This allows a range to end at the largest value of its type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Inclusive;
+
+impl sealed::Sealed for Exclusive {}
+impl sealed::Sealed for Inclusive {}
+
+impl BoundKind for Exclusive {
+    const INCLUSIVE: bool = false;
+}
+
+impl BoundKind for Inclusive {
+    const INCLUSIVE: bool = true;
+}
+
+/// Converts an upper bound of one kind to the equivalent upper bound of another kind.
+/// Returns `None` if that bound lies outside of the domain of `T`.
+fn convert_end<T: Step, From: BoundKind, To: BoundKind>(end: T) -> Option<T> {
+    match (From::INCLUSIVE, To::INCLUSIVE) {
+        (true, false) => Step::forward_checked(end, 1),
+        (false, true) => Step::backward_checked(end, 1),
+        _ => Some(end)
+    }
+}
 
 /// A contiguous range with a lower and upper bound.
-/// Prefer this over [`std::iter::range`] for computations where iteration is not required
+/// Prefer this over [`std::iter::range`] for computations where iteration is not required.
+///
+/// The lower bound is always part of the range,
+/// the [`BoundKind`] decides whether the upper bound is as well.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub struct Range<T = i32> {
+pub struct Range<T = i32, B: BoundKind = Exclusive> {
     pub start: T,
-    pub end: T
+    pub end: T,
+    bound: PhantomData<B>
 }
 
-impl<T> Range<T> {
+impl<T> Range<T, Exclusive> {
     /// Produces the empty range: `(0..0)`.
     #[must_use]
     pub fn empty() -> Self where
         T: Zero
     {
-        Self { start: T::zero(), end: T::zero() }
+        Self::new_unchecked(T::zero(), T::zero())
     }
 
     /// Creates a range with an exclusive upperbound.
@@ -32,27 +75,74 @@ impl<T> Range<T> {
     pub fn exclusive(start: T, end: T) -> Result<Self, InvalidRangeError<T>> where
         T: PartialOrd
     {
-        let range = Self { start, end };
-        if range.start <= range.end { Ok(range) } else { Err(InvalidRangeError(range)) }
+        Self::new(start, end)
+    }
+
+    /// Remove a given range from this one, returning the part before and after it.
+    /// If the other range is not entirely contained in this one, a single range is returned
+    /// that contains just the part before or after it, whichever is applicable.
+    pub fn split_range(self, other: Self) -> (Self, Option<Self>) where
+        T: Ord + Copy
+    {
+        if self.contains_range(&other) {
+            (
+                Self::new_unchecked(self.start, other.start),
+                Some(Self::new_unchecked(other.end, self.end))
+            )
+        } else if other.end < self.end {
+            (Self::new_unchecked(max(self.start, other.end), self.end), None)
+        } else {
+            (Self::new_unchecked(self.start, min(self.end, other.start)), None)
+        }
     }
+}
 
-    // TODO: inclusive ranges should be represented using a tag in the type system
-    // This method is flawed because not the entire domain of `T` fits in the range
+impl<T> Range<T, Inclusive> {
     /// Creates a range with an inclusive upperbound.
     /// If `start > end` this returns an error.
-    /// Additionally, this function panics if `end == T::MAX`.
-    pub fn inclusive(start: T, end: T) -> Result<Self, InvalidRangeError<T>> where
-        T: Step + PartialOrd
+    pub fn inclusive(start: T, end: T) -> Result<Self, InvalidRangeError<T, Inclusive>> where
+        T: PartialOrd
+    {
+        Self::new(start, end)
+    }
+}
+
+impl<T, B: BoundKind> Range<T, B> {
+    /// Creates a range without checking that `start <= end`.
+    #[must_use]
+    pub const fn new_unchecked(start: T, end: T) -> Self {
+        Self { start, end, bound: PhantomData }
+    }
+
+    fn new(start: T, end: T) -> Result<Self, InvalidRangeError<T, B>> where
+        T: PartialOrd
     {
-        let range = Self { start, end: T::forward(end, 1) };
+        let range = Self::new_unchecked(start, end);
         if range.start <= range.end { Ok(range) } else { Err(InvalidRangeError(range)) }
     }
 
+    /// Converts the range to a range with a different kind of upper bound.
+    /// Returns `None` if the new upper bound can't be represented,
+    /// or if the range is empty and converted to an inclusive range.
+    /// ```
+    /// # use aoc_lib::{math::{Exclusive, Inclusive, Range}, range};
+    /// assert_eq!(Some(range!(1..256)), range!(1u16..=255).convert::<Exclusive>());
+    /// assert_eq!(None, range!(1u8..=255).convert::<Exclusive>());
+    /// assert_eq!(None, range!(3..3).convert::<Inclusive>());
+    /// ```
+    #[must_use]
+    pub fn convert<To: BoundKind>(self) -> Option<Range<T, To>>
+        where T: Step
+    {
+        let end = convert_end::<T, B, To>(self.end)?;
+        (self.start <= end || !To::INCLUSIVE).then(|| Range::new_unchecked(self.start, end))
+    }
+
     /// Returns whether the item is contained in the range or not
     pub fn contains(&self, value: &T) -> bool
         where T: PartialOrd
     {
-        &self.start <= value && value < &self.end
+        &self.start <= value && if B::INCLUSIVE { value <= &self.end } else { value < &self.end }
     }
 
     /// Returns whether another range is a subset of this range
@@ -63,23 +153,55 @@ impl<T> Range<T> {
     }
 
     /// The amount of items in the range.
-    /// Note that this will panic on an inclusive range of `0..=MAX`
-    /// as the amount of items in the range is `MAX + 1`
+    /// Note that this will overflow on an inclusive range of `0..=MAX`
+    /// as the amount of items in the range is `MAX + 1`,
+    /// use [`checked_interval`](Self::checked_interval) or [`interval_as`](Self::interval_as) instead.
     pub fn interval(self) -> T
         where T: Ord + One + Sub<Output=T> + Add<Output=T>
     {
-        self.end - self.start
+        let difference = self.end - self.start;
+        if B::INCLUSIVE { difference + T::one() } else { difference }
+    }
+
+    /// The amount of items in the range, `None` if it does not fit in `T`.
+    /// ```
+    /// # use aoc_lib::range;
+    /// assert_eq!(Some(255), range!(1u8..=255).checked_interval());
+    /// assert_eq!(None, range!(0u8..=255).checked_interval());
+    /// ```
+    pub fn checked_interval(self) -> Option<T>
+        where T: One + CheckedSub + CheckedAdd
+    {
+        let difference = self.end.checked_sub(&self.start)?;
+        if B::INCLUSIVE { difference.checked_add(&T::one()) } else { Some(difference) }
     }
 
-    /// Attempts to merge the two ranges.
-    /// If they partially overlap, an Ok result with all items from both ranges is returned.
-    /// If they are disjoint, Err is returned with a tuple containing the two original ranges.
-    pub fn merge(self, other: Self) -> Result<Self, (Self, Self)>
-        where T: Ord
+    /// The amount of items in the range, counted in a wider type that can hold it.
+    /// ```
+    /// # use aoc_lib::range;
+    /// assert_eq!(256, range!(0u8..=255).interval_as::<u16>());
+    /// ```
+    pub fn interval_as<U>(self) -> U
+        where U: From<T> + One + Sub<Output=U> + Add<Output=U>
     {
-        if other.start <= self.end {
-            Ok(Self { start: self.start,end: max(self.end, other.end) })
-        } else { Err((self, other)) }
+        let difference = U::from(self.end) - U::from(self.start);
+        if B::INCLUSIVE { difference + U::one() } else { difference }
+    }
+
+    /// Attempts to merge the two ranges, the other range should not start before this one.
+    /// If they overlap or are adjacent, an Ok result with all items from both ranges is returned.
+    /// If they are disjoint, or the merged range can't be represented,
+    /// Err is returned with a tuple containing the two original ranges.
+    pub fn merge<Other: BoundKind>(self, other: Range<T, Other>) -> Result<Self, (Self, Range<T, Other>)>
+        where T: Ord + Step
+    {
+        let touches = other.start <= self.end
+            || (B::INCLUSIVE && Step::forward_checked(self.end.clone(), 1).as_ref() == Some(&other.start));
+
+        match convert_end::<T, Other, B>(other.end.clone()) {
+            Some(end) if touches => Ok(Self::new_unchecked(self.start, max(self.end, end))),
+            _ => Err((self, other))
+        }
     }
 
     /// Sums the entire range of numbers.
@@ -89,52 +211,83 @@ impl<T> Range<T> {
            Sub<Output=T> + Add<Output=T> + Div<Output=T>
     {
         let amount = self.clone().interval();
-        let end = self.end - T::one();
+        let last = if B::INCLUSIVE { self.end } else { self.end - T::one() };
 
-        (self.start + end) * amount / T::from_u8(2).unwrap()
-    }
-
-    /// Remove a given range from this one, returning the part before and after it.
-    /// If the other range is not entirely contained in this one, a single range is returned
-    /// that contains just the part before or after it, whichever is applicable.
-    pub fn split_range(self, other: Self) -> (Self, Option<Self>) where
-        T: Ord + Copy
-    {
-        if self.contains_range(&other) {
-            (
-                Self { start: self.start, end: other.start },
-                Some(Self { start: other.end, end: self.end })
-            )
-        } else if other.end < self.end {
-            (Self { start: max(self.start, other.end), end: self.end }, None)
-        } else {
-            (Self { start: self.start, end: min(self.end, other.start) }, None)
-        }
+        (self.start + last) * amount / T::from_u8(2).unwrap()
     }
 }
 
-impl<T: Display> Display for Range<T> {
+impl<T: Display, B: BoundKind> Display for Range<T, B> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}..{})", self.start, self.end)
+        let separator = if B::INCLUSIVE { "..=" } else { ".." };
+        write!(f, "({}{separator}{})", self.start, self.end)
     }
 }
 
-impl<T: Default> Default for Range<T> where {
+impl<T: Default> Default for Range<T> {
     fn default() -> Self {
-        Self { start: T::default(), end: T::default() }
+        Self::new_unchecked(T::default(), T::default())
     }
 }
 
 impl<T: Step> IntoIterator for Range<T> {
     type Item = T;
-    type IntoIter = core::ops::Range<T>;
+    type IntoIter = ops::Range<T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.start..self.end
+        self.into()
     }
 }
 
-impl<T: PartialOrd> PartialOrd for Range<T> {
+impl<T: Step> IntoIterator for Range<T, Inclusive> {
+    type Item = T;
+    type IntoIter = RangeInclusive<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into()
+    }
+}
+
+impl<T> From<Range<T>> for ops::Range<T> {
+    fn from(range: Range<T>) -> Self {
+        range.start..range.end
+    }
+}
+
+impl<T> From<Range<T, Inclusive>> for RangeInclusive<T> {
+    fn from(range: Range<T, Inclusive>) -> Self {
+        range.start..=range.end
+    }
+}
+
+impl<T: PartialOrd> TryFrom<ops::Range<T>> for Range<T> {
+    type Error = InvalidRangeError<T>;
+
+    fn try_from(range: ops::Range<T>) -> Result<Self, Self::Error> {
+        Self::exclusive(range.start, range.end)
+    }
+}
+
+impl<T: PartialOrd> TryFrom<RangeInclusive<T>> for Range<T, Inclusive> {
+    type Error = InvalidRangeError<T, Inclusive>;
+
+    fn try_from(range: RangeInclusive<T>) -> Result<Self, Self::Error> {
+        let (start, end) = range.into_inner();
+        Self::inclusive(start, end)
+    }
+}
+
+impl<T, B: BoundKind> RangeBounds<T> for Range<T, B> {
+    fn start_bound(&self) -> ops::Bound<&T> {
+        ops::Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> ops::Bound<&T> {
+        if B::INCLUSIVE { ops::Bound::Included(&self.end) } else { ops::Bound::Excluded(&self.end) }
+    }
+}
+
+impl<T: PartialOrd, B: BoundKind> PartialOrd for Range<T, B> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match self.start.partial_cmp(&other.start) {
             Some(Ordering::Equal) => self.end.partial_cmp(&other.end),
@@ -143,7 +296,7 @@ impl<T: PartialOrd> PartialOrd for Range<T> {
     }
 }
 
-impl<T: Ord> Ord for Range<T> {
+impl<T: Ord, B: BoundKind> Ord for Range<T, B> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.start.cmp(&other.start)
             .then_with(|| self.end.cmp(&other.end))
@@ -155,14 +308,14 @@ macro_rules! range {
     ($start: literal..$end: literal) => {
         {
             const { assert!($start <= $end) };
-            $crate::math::Range { start: $start, end: $end }
+            $crate::math::Range::<_, $crate::math::Exclusive>::new_unchecked($start, $end)
         }
     };
 
     ($start: literal..=$end: literal) => {
         {
             const { assert!($start <= $end) };
-            $crate::math::Range { start: $start, end: $end + 1 }
+            $crate::math::Range::<_, $crate::math::Inclusive>::new_unchecked($start, $end)
         }
     }
 }
@@ -178,9 +331,10 @@ mod tests {
         assert_eq!(Ok(range!(5..6)), Range::exclusive(5, 6));
         assert_eq!(Ok(range!(6..=8)), Range::inclusive(6, 8));
 
-        let error = Result::<Range, _>::Err(InvalidRangeError(Range { start: 5, end: 3 }));
-        assert_eq!(error, Range::exclusive(5, 3));
-        assert_eq!(error, Range::inclusive(5, 2));
+        assert_eq!(Err(InvalidRangeError(Range::new_unchecked(5, 3))), Range::exclusive(5, 3));
+        assert_eq!(Err(InvalidRangeError(Range::new_unchecked(5, 2))), Range::inclusive(5, 2));
+
+        assert_eq!(Ok(range!(1u8..=255)), Range::inclusive(1, u8::MAX));
     }
 
     #[test]
@@ -196,6 +350,15 @@ mod tests {
         assert_eq!(7, range!(3..10).interval());
         assert_eq!(1, range!(1..=1).interval());
         assert_eq!(3, range!(4..=6).interval());
+        assert_eq!(255, range!(1u8..=255).interval());
+
+        let full = Range::inclusive(0, u8::MAX).unwrap();
+        assert_eq!(None, full.checked_interval());
+        assert_eq!(256, full.interval_as::<u16>());
+
+        let almost_full = Range::exclusive(0, u8::MAX).unwrap();
+        assert_eq!(Some(255), almost_full.checked_interval());
+        assert_eq!(255, almost_full.interval_as::<u16>());
     }
 
     #[test]
@@ -239,6 +402,29 @@ mod tests {
         assert_eq!((range, None), actual);
     }
 
+    #[test]
+    fn merge_inclusive() {
+        let actual = range!(1u8..=100).merge(range!(101u8..=255));
+        assert_eq!(Ok(range!(1..=255)), actual);
+
+        let first = range!(1u8..=100);
+        let second = range!(102u8..=255);
+        assert_eq!(Err((first, second)), first.merge(second));
+
+        // The merged range would end at 256
+        let first = range!(1u8..10);
+        let second = range!(5u8..=255);
+        assert_eq!(Err((first, second)), first.merge(second));
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Ok(range!(3..7)), Range::try_from(3..7));
+        assert_eq!(Ok(range!(3..=7)), Range::try_from(3..=7));
+        assert_eq!(3..=7, std::ops::RangeInclusive::from(range!(3..=7)));
+        assert_eq!(vec![254, 255], range!(254u8..=255).into_iter().collect::<Vec<_>>());
+    }
+
     #[test]
     fn test_cmp() {
         assert!(range!(1..2) > range!(0..5));
@@ -0,0 +1,244 @@
+use std::{cmp::{max, min}, fmt::Display, ops::{Add, Sub}};
+
+use itertools::Itertools;
+use num::Zero;
+
+use super::Range;
+
+/// A set of values made up of disjoint [`Range`]s.
+///
+/// The ranges are kept sorted, empty ranges are dropped and overlapping or adjacent ranges are merged.
+/// This means every set has exactly one representation.
+/// ```
+/// # use aoc_lib::{math::RangeSet, range};
+/// let set: RangeSet = [range!(1..4), range!(3..6), range!(8..10)].into_iter().collect();
+/// assert_eq!(&[range!(1..6), range!(8..10)], set.ranges());
+/// assert_eq!(7, set.length());
+/// assert!(set.contains(&9));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T = i32> {
+    ranges: Vec<Range<T>>
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T> RangeSet<T> {
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    /// The disjoint ranges that make up the set, in ascending order.
+    #[must_use]
+    pub fn ranges(&self) -> &[Range<T>] { &self.ranges }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool { self.ranges.is_empty() }
+
+    /// The smallest range containing every value in the set.
+    #[must_use]
+    pub fn bounds(&self) -> Option<Range<T>>
+        where T: Copy
+    {
+        let first = self.ranges.first()?;
+        let last = self.ranges.last()?;
+        Some(Range::new_unchecked(first.start, last.end))
+    }
+
+    /// The amount of values in the set.
+    #[must_use]
+    pub fn length(&self) -> T
+        where T: Copy + Zero + Sub<Output=T>
+    {
+        self.ranges
+            .iter()
+            .fold(T::zero(), |length, range| length + (range.end - range.start))
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool
+        where T: Ord
+    {
+        let index = self.ranges.partition_point(|range| &range.end <= value);
+        self.ranges.get(index).is_some_and(|range| range.contains(value))
+    }
+}
+
+impl<T: Ord + Copy> RangeSet<T> {
+    pub fn insert(&mut self, range: Range<T>) {
+        *self = self.union(&range.into());
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).copied().collect()
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (self.ranges.iter().peekable(), other.ranges.iter().peekable());
+
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            let start = max(a.start, b.start);
+            let end = min(a.end, b.end);
+            if start < end { ranges.push(Range::new_unchecked(start, end)); }
+
+            if a.end < b.end { left.next(); } else { right.next(); }
+        }
+
+        Self { ranges }
+    }
+
+    /// The values in this set that are not in the other set.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut first_overlapping = 0;
+
+        for range in &self.ranges {
+            let mut start = range.start;
+            while other.ranges.get(first_overlapping).is_some_and(|hole| hole.end <= start) {
+                first_overlapping += 1;
+            }
+
+            for hole in other.ranges[first_overlapping..].iter().take_while(|hole| hole.start < range.end) {
+                if start < hole.start { ranges.push(Range::new_unchecked(start, hole.start)); }
+                start = max(start, hole.end);
+            }
+
+            if start < range.end { ranges.push(Range::new_unchecked(start, range.end)); }
+        }
+
+        Self { ranges }
+    }
+
+    /// The values within the bounds that are not in this set.
+    #[must_use]
+    pub fn complement(&self, bounds: Range<T>) -> Self {
+        Self::from(bounds).difference(self)
+    }
+
+    /// Maps the set through a piecewise function.
+    /// Every piece consists of a source range and the value the start of that range is mapped to,
+    /// the rest of the range is shifted along with it.
+    /// Values outside of every source range are mapped onto themselves.
+    ///
+    /// The source ranges should be disjoint.
+    /// ```
+    /// # use aoc_lib::{math::RangeSet, range};
+    /// let set = RangeSet::from(range!(0..10));
+    /// let mapped = set.map_pieces([(range!(2..4), 20), (range!(8..12), 0)]);
+    /// assert_eq!(&[range!(0..2), range!(4..8), range!(20..22)], mapped.ranges());
+    /// ```
+    #[must_use]
+    pub fn map_pieces(&self, pieces: impl IntoIterator<Item=(Range<T>, T)>) -> Self
+        where T: Add<Output=T> + Sub<Output=T>
+    {
+        let mut unmapped = self.clone();
+        let mut mapped = Vec::new();
+
+        for (source, destination) in pieces {
+            let source = Self::from(source);
+            let shift = |value: T| value - source.ranges[0].start + destination;
+
+            mapped.extend(unmapped
+                .intersection(&source)
+                .ranges
+                .into_iter()
+                .map(|range| Range::new_unchecked(shift(range.start), shift(range.end)))
+            );
+
+            unmapped = unmapped.difference(&source);
+            if unmapped.is_empty() { break }
+        }
+
+        mapped.into_iter().chain(unmapped.ranges).collect()
+    }
+}
+
+impl<T: Ord + Copy> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item=Range<T>>>(iter: I) -> Self {
+        let ranges = iter.into_iter()
+            .filter(|range| range.start < range.end)
+            .sorted_unstable()
+            .coalesce(|left, right| if right.start <= left.end {
+                Ok(Range::new_unchecked(left.start, max(left.end, right.end)))
+            } else { Err((left, right)) })
+            .collect();
+
+        Self { ranges }
+    }
+}
+
+impl<T: Ord + Copy> Extend<Range<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item=Range<T>>>(&mut self, iter: I) {
+        *self = self.ranges.iter().copied().chain(iter).collect();
+    }
+}
+
+impl<T> IntoIterator for RangeSet<T> {
+    type Item = Range<T>;
+    type IntoIter = std::vec::IntoIter<Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+impl<T: Display> Display for RangeSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{{}}}", self.ranges.iter().join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{math::{Range, RangeSet}, range};
+
+    fn set(ranges: &[Range]) -> RangeSet {
+        ranges.iter().copied().collect()
+    }
+
+    #[test]
+    fn normalization() {
+        let actual = set(&[range!(8..10), range!(3..3), range!(1..4), range!(4..6)]);
+        assert_eq!(&[range!(1..6), range!(8..10)], actual.ranges());
+    }
+
+    #[test]
+    fn intersection() {
+        let actual = set(&[range!(1..5), range!(7..12)]).intersection(&set(&[range!(3..8), range!(10..20)]));
+        assert_eq!(set(&[range!(3..5), range!(7..8), range!(10..12)]), actual);
+    }
+
+    #[test]
+    fn difference() {
+        let actual = set(&[range!(1..10), range!(12..15)]).difference(&set(&[range!(0..2), range!(4..5), range!(9..13)]));
+        assert_eq!(set(&[range!(2..4), range!(5..9), range!(13..15)]), actual);
+    }
+
+    #[test]
+    fn complement() {
+        let actual = set(&[range!(2..4), range!(6..8)]).complement(range!(0..7));
+        assert_eq!(set(&[range!(0..2), range!(4..6)]), actual);
+    }
+
+    #[test]
+    fn contains() {
+        let set = set(&[range!(2..4), range!(6..8)]);
+        assert!(set.contains(&2));
+        assert!(!set.contains(&4));
+        assert!(set.contains(&7));
+        assert!(!set.contains(&8));
+    }
+}
\ No newline at end of file
@@ -1,13 +1,16 @@
 mod combinators;
 mod parsers;
 mod errors;
+mod normalization;
 
 use nom::{IResult, Parser, character::complete::{u8, u16, u32, u64, u128, i8, i16, i32, i64, i128}};
 use nom_language::error::VerboseError;
+use num::BigInt;
 
 pub use parsers::*;
 pub use combinators::*;
 pub use errors::*;
+pub use normalization::*;
 
 pub type TextParserResult<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
 
@@ -59,4 +62,5 @@ impl_parsable!(i64, i64);
 impl_parsable!(i128, i128);
 impl_parsable!(isize, isize);
 impl_parsable!(f32, f32);
-impl_parsable!(f64, f64);
\ No newline at end of file
+impl_parsable!(f64, f64);
+impl_parsable!(BigInt, bigint);
\ No newline at end of file
@@ -0,0 +1,65 @@
+use std::borrow::Cow;
+
+/// A pipeline of transformations that is applied to a puzzle input before it is handed to a solver.
+/// Stray whitespace like a trailing newline trips up parsers that have to consume the entire input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Normalization {
+    /// Remove the byte order mark at the start of the input.
+    pub strip_bom: bool,
+    /// Replace ``\r\n`` line endings with ``\n``.
+    pub normalize_newlines: bool,
+    /// Remove any line endings at the end of the input.
+    pub trim_trailing_newline: bool
+}
+
+impl Normalization {
+    /// Applies every normalization step.
+    #[must_use]
+    pub const fn standard() -> Self {
+        Self {
+            strip_bom: true,
+            normalize_newlines: true,
+            trim_trailing_newline: true
+        }
+    }
+
+    /// Leaves the input untouched.
+    /// For solvers that rely on the raw whitespace of the input.
+    #[must_use]
+    pub const fn raw() -> Self {
+        Self {
+            strip_bom: false,
+            normalize_newlines: false,
+            trim_trailing_newline: false
+        }
+    }
+
+    /// Normalizes the input, this only allocates when line endings have to be replaced.
+    /// ```
+    /// # use aoc_lib::parsing::Normalization;
+    /// let input = "\u{feff}#.#\r\n.#.\r\n";
+    /// assert_eq!("#.#\n.#.", Normalization::standard().apply(input));
+    /// assert_eq!(input, Normalization::raw().apply(input));
+    /// ```
+    #[must_use]
+    pub fn apply(self, input: &str) -> Cow<'_, str> {
+        let input = if self.strip_bom {
+            input.strip_prefix('\u{feff}').unwrap_or(input)
+        } else { input };
+
+        let input = if self.trim_trailing_newline {
+            input.trim_end_matches(['\r', '\n'])
+        } else { input };
+
+        if self.normalize_newlines && input.contains('\r') {
+            Cow::Owned(input.replace("\r\n", "\n"))
+        } else { Cow::Borrowed(input) }
+    }
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
\ No newline at end of file
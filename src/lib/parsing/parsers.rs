@@ -1,6 +1,7 @@
 use nom::{
-    character::complete::{u64, i64}, Parser,
+    character::complete::{u64, i64, digit1, one_of}, combinator::{map_res, opt, recognize}, Parser,
 };
+use num::BigInt;
 
 use super::TextParserResult;
 
@@ -14,6 +15,14 @@ pub fn isize(input: &str) -> TextParserResult<'_, isize> {
     i64.map(|n| n as isize).parse(input)
 }
 
+/// Parses a signed integer of arbitrary size.
+pub fn bigint(input: &str) -> TextParserResult<'_, BigInt> {
+    map_res(
+        recognize((opt(one_of("+-")), digit1)),
+        str::parse
+    ).parse(input)
+}
+
 /// Note that this function only parses whole integers and *converts* them to floats.
 /// It does not parse anything with decimal places.
 #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
@@ -19,6 +19,7 @@
 use std::fs::File;
 
 use ahash::HashMap;
+use aoc_lib::parsing::Normalization;
 use jikan::{DayManifest, ManifestProvider, Puzzle};
 
 mod solvers;
@@ -28,16 +29,27 @@ type Solver = jikan::Solver<anyhow::Error>;
 
 macro_rules! solver {
     ($year: literal, $day: literal, $part: literal) => {
+        solver!($year, $day, $part, Normalization::standard())
+    };
+
+    // Solvers that rely on the raw whitespace of their input can opt out by passing `Normalization::raw()`
+    ($year: literal, $day: literal, $part: literal, $normalization: expr) => {
         vec![(
             Puzzle { year: $year, day: $day.parse().unwrap(), part: $part },
-            paste::expr! { solvers::[<year_ $year>]::[<day_ $day>]::[<solve_part_ $part>] } as Solver
+            (|input: &str| paste::expr! {
+                solvers::[<year_ $year>]::[<day_ $day>]::[<solve_part_ $part>](&$normalization.apply(input))
+            }) as Solver
         )]
     };
 
     ($year: literal, $day: literal) => {
+        solver!($year, $day, Normalization::standard())
+    };
+
+    ($year: literal, $day: literal, $normalization: expr) => {
         [
-            solver!($year, $day, 1),
-            solver!($year, $day, 2)
+            solver!($year, $day, 1, $normalization),
+            solver!($year, $day, 2, $normalization)
         ].into_iter()
             .flatten()
             .collect::<Vec<_>>()
@@ -133,10 +145,10 @@ fn main() {
         solver!(2023, "18"),
         solver!(2023, "19"),
         solver!(2023, "20"),
-        solver!(2023, "21", 1),
+        solver!(2023, "21"),
         solver!(2023, "22"),
         solver!(2023, "23"),
-        solver!(2023, "24", 1),
+        solver!(2023, "24"),
         solver!(2023, "25", 1),
 
         solver!(2024, "01"),
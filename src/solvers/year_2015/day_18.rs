@@ -1,45 +1,33 @@
-use aoc_lib::{geometry::{Point2D, grid::{Grid, GridLike, BitGrid, GridLikeMut}, Direction2D}, functional::{repeat_apply, consume}, math::Bit, iteration::ExtraIter};
+use aoc_lib::{geometry::{Direction2D, grid::{Automaton, Grid, GridLike, BitGrid}}, math::Bit, iteration::ExtraIter};
 use crate::SolverResult;
 
-fn neighbours_on(grid: &Grid<Bit>, cell: Point2D<usize>) -> usize {
-    cell.neighbours::<isize, _>(Direction2D::all())
-        .count_where(|&location| grid.get(location).is_some_and(|light| light.is_on()))
+fn conway(light: Bit, neighbours: &[&Bit]) -> Bit {
+    match (light, neighbours.iter().count_where(|light| light.is_on())) {
+        | (Bit::On, 2 | 3)
+        | (Bit::Off, 3) => Bit::On,
+        _ => Bit::Off
+    }
 }
 
-fn next_state(grid: &Grid<Bit>) -> Grid<Bit> {
-    grid.clone().enumerate_map(|location, light| {
-        match (light, neighbours_on(grid, location)) {
-            | (Bit::On, 2 | 3)
-            | (Bit::Off, 3) => Bit::On,
-            _ => Bit::Off
-        }
-    })
-}
-
-fn fix_corners(grid: &mut Grid<Bit>) {
-    let corners = grid.area().corners();
-
-    for location in corners {
-        *grid.get_mut(location).unwrap() = Bit::On;
-    }
+fn automaton() -> Automaton<Bit, Direction2D, impl Fn(&Bit, &[&Bit]) -> Bit> {
+    Automaton::new(Direction2D::all(), |&light, neighbours| conway(light, neighbours))
 }
 
 pub fn solve_part_1(input: &str) -> SolverResult {
-    let grid = Grid::parse(input)?;
-    let grid = repeat_apply(100, grid, consume(next_state));
+    let grid = Grid::<Bit>::parse(input)?;
+    let grid = automaton().run(grid, 100);
 
     Ok(Box::new(grid.pop_count()))
 }
 
 pub fn solve_part_2(input: &str) -> SolverResult {
     let grid = Grid::<Bit>::parse(input)?;
+    let automaton = grid.area()
+        .corners()
+        .into_iter()
+        .fold(automaton(), |automaton, corner| automaton.pin(corner, Bit::On));
 
-    let mut grid = repeat_apply(100, grid, |mut grid| {
-        fix_corners(&mut grid);
-        next_state(&grid)
-    });
-
-    fix_corners(&mut grid);
+    let grid = automaton.run(grid, 100);
 
     Ok(Box::new(grid.pop_count()))
 }
\ No newline at end of file
@@ -1,54 +1,10 @@
-use std::{f32::consts::E, iter::repeat};
+use std::f32::consts::E;
 
+use aoc_lib::math::Sieve;
 use crate::SolverResult;
-use itertools::Itertools;
 
 const GAMMA: f32 = 0.577_215_7;
 
-fn prime_factors(mut n: usize, primes: &[usize]) -> Vec<usize> {
-    let mut factors = Vec::new();
-    
-    while n != 1 {
-        let factor = smallest_prime_factor(n, primes);
-
-        factors.push(factor);
-        n /= factor;
-    }
-
-    factors
-}
-
-fn factors(n: usize, limit: usize) -> Vec<usize> {
-    (1..limit).filter(|&x| n.is_multiple_of(x)).collect()
-}
-
-fn smallest_prime_factor(n: usize, primes: &[usize]) -> usize {
-    *primes.iter()
-        .find(|&x| n % x == 0)
-        .unwrap_or_else(|| panic!("Failed to compute spf({n})"))
-}
-
-fn sieve_eratosthenes(n: usize) -> Vec<usize> {
-    let mut prime_table: Vec<bool> = repeat(true).take(n).collect();
-    
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
-    let root_n = f32::sqrt(n as f32) as usize;
-    for i in 2..root_n {
-        if !*prime_table.get(i).unwrap() { continue; }
-        let mut j = i * i;
-        while j < n {
-            prime_table[j] = false;
-            j += i;
-        }
-    }
-
-    prime_table.into_iter()
-        .skip(2) // 0 and 1
-        .enumerate()
-        .filter_map(|(i, x)| x.then_some(i + 2))
-        .collect()
-}
-
 fn lower_bound_sum_of_divisors(sum: usize) -> usize {
     // Robin's theorem
     fn sum_upper_bound(sum: usize) -> usize {
@@ -80,21 +36,15 @@ fn lower_bound_sum_of_divisors(sum: usize) -> usize {
 }
 
 fn first_house_with_n_presents_infinite(presents: usize) -> usize {
-    fn presents_for_house(house: usize, primes: &[usize]) -> usize {
-        prime_factors(house, primes)
-            .into_iter()
-            .counts()
-            .into_iter()
-            .map(|(base, amount)| {
-                (1..=amount).fold(1, |acc, exponent| {
-                    acc + base.pow(exponent.try_into().unwrap())
-                })
-            }).product::<usize>() * 10
-    }
+    let sieve = Sieve::new(presents / 10);
+    let presents_for_house = |house: usize| {
+        sieve.factorize(house)
+            .unwrap_or_else(|| panic!("Failed to factorize {house}"))
+            .divisor_sum() * 10
+    };
 
-    let primes = sieve_eratosthenes(presents / 10);
     let mut house = lower_bound_sum_of_divisors(presents / 10);
-    while presents_for_house(house, &primes) < presents {
+    while presents_for_house(house) < presents {
         house += 1;
     }
 
@@ -102,13 +52,17 @@ fn first_house_with_n_presents_infinite(presents: usize) -> usize {
 }
 
 fn first_house_with_n_presents_finite(presents: usize, houses_per_elf: usize) -> usize {
+    let upper_bound = presents / 11;
+    let sieve = Sieve::new(upper_bound);
     let presents_for_house = |house: usize| {
-        factors(house, houses_per_elf)
-            .into_iter().map(|x| house / x)
+        sieve.factorize(house)
+            .unwrap_or_else(|| panic!("Failed to factorize {house}"))
+            .divisors()
+            .into_iter()
+            .filter(|&elf| house / elf < houses_per_elf)
             .sum::<usize>() * 11
     };
 
-    let upper_bound = presents / 11;
     (2..upper_bound).find(|&house| presents_for_house(house) >= presents)
         .unwrap()
 }
@@ -1,27 +1,19 @@
-use aoc_lib::parsing::{skip_until, TextParser, ParseError};
+use aoc_lib::{math::mod_pow, parsing::{skip_until, TextParser, ParseError}};
 use crate::SolverResult;
 use nom::{character::complete::{u32, char}, bytes::complete::tag, sequence::{preceded, delimited}, Parser};
 
-#[derive(Debug, PartialEq, Eq)]
 struct Position(u32, u32);
 
 impl Position {
-    const fn next(&self) -> Self {
+    /// The amount of steps along the diagonals from the top left to this position.
+    fn index(&self) -> u64 {
         let &Self(col, row) = self;
-        if row > 1 { Self(col + 1, row - 1) }
-        else { Self(1, col + 1) }
+        let diagonal = u64::from(row + col - 1);
+        diagonal * (diagonal - 1) / 2 + u64::from(col) - 1
     }
 
     fn code(&self) -> u64 {
-        let mut code: u64 = 2015_1125;
-        let mut position = Self(1, 1);
-
-        while &position != self {
-            position = position.next();
-            code = (code * 252_533) % 33_554_393;
-        }
-
-        code
+        2015_1125 * mod_pow(252_533, self.index(), 33_554_393) % 33_554_393
     }
 }
 
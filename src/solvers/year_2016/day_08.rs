@@ -1,12 +1,11 @@
 use anyhow::Result;
-use aoc_lib::{geometry::{Dimensions, grid::{Grid, InvalidGridAreaError, GridLikeMut, BitGrid}}, parsing::{TextParser, parse_lines, ParseError, usize, Parsable}, math::Bit};
+use aoc_lib::{geometry::{Dimensions, grid::{Grid, InvalidGridAreaError, GridLikeMut, BitGrid}}, parsing::{TextParser, parse_lines, ParseError, usize, isize, Parsable}, math::Bit};
 use crate::SolverResult;
-use itertools::Itertools;
 use nom::{sequence::preceded, bytes::complete::tag, Parser, branch::alt};
 
 struct RotateInstruction {
     index: usize,
-    amount: usize
+    amount: isize
 }
 
 enum Instruction {
@@ -17,7 +16,7 @@ enum Instruction {
 
 impl Instruction {
     fn parse(input: &str) -> Result<Self, ParseError> {
-        let rotate_instruction = || usize.and(preceded(tag(" by "), usize))
+        let rotate_instruction = || usize.and(preceded(tag(" by "), isize))
             .map(|(index, amount)| RotateInstruction { index, amount });
 
         let rotate_row = preceded(tag("rotate row y="), rotate_instruction()).map(Instruction::RotateRow);
@@ -32,35 +31,14 @@ impl Instruction {
             Self::Fill(dimensions) => {
                 grid.sub_grid_mut(dimensions.into())?.fill(Bit::On);
             },
-            Self::RotateRow(RotateInstruction { index, amount }) => {
-                let row = grid.get_row_mut(index)
-                    .unwrap_or_else(|| panic!("Grid row index out of range: {index}"));
-
-                rotate_slice(row, amount);
-            },
-            Self::RotateColumn(RotateInstruction { index, amount }) => {
-                let column = grid.get_column_mut(index)
-                    .unwrap_or_else(|| panic!("Grid column index out of range: {index}"));
-
-                let mut rotated = column.iter()
-                    .map(|light| **light)
-                    .collect_vec();
-
-                rotate_slice(&mut rotated, amount);
-                column.into_iter()
-                    .zip(rotated)
-                    .for_each(|(cell, value)| *cell = value);
-            }
+            Self::RotateRow(RotateInstruction { index, amount }) => grid.shift_row(index, amount),
+            Self::RotateColumn(RotateInstruction { index, amount }) => grid.shift_column(index, amount)
         }
 
         Ok(grid)
     }
 }
 
-fn rotate_slice<T>(slice: &mut [T], amount: usize) {
-    slice.rotate_right(amount % slice.len());
-}
-
 fn final_grid(input: &str) -> Result<Grid<Bit>> {
     let grid = Grid::<Bit>::empty(Dimensions(50, 6));
     let instructions = parse_lines(Instruction::parse, input)?;
@@ -1,29 +1,29 @@
-use aoc_lib::parsing::{ParseError, TextParser, parse_lines, usize};
+use aoc_lib::{math::chinese_remainder, parsing::{ParseError, TextParser, parse_lines, isize}};
 use yuki::errors::NoSolution;
 use crate::SolverResult;
 use nom::{bytes::complete::tag, sequence::{delimited, terminated}, character::complete::char, Parser};
 
 struct Disc {
-    positions: usize,
-    starting_position: usize,
-    index: usize
+    positions: isize,
+    starting_position: isize,
+    index: isize
 }
 
 impl Disc {
     fn parse(input: &str) -> Result<Self, ParseError> {
-        let disc_number = delimited(tag("Disc #"), usize, tag(" has "));
-        let positions = terminated(usize, tag(" positions; at time="));
-        let time = terminated(usize, tag(", it is at position "));
-        let starting_position = delimited(time, usize, char('.'));
+        let disc_number = delimited(tag("Disc #"), isize, tag(" has "));
+        let positions = terminated(isize, tag(" positions; at time="));
+        let time = terminated(isize, tag(", it is at position "));
+        let starting_position = delimited(time, isize, char('.'));
 
         (disc_number, positions, starting_position)
             .map(|(index, positions, starting_position)| Self { positions, starting_position, index })
             .run(input)
     }
 
-    const fn is_aligned_at(&self, time: usize) -> bool {
-        (self.starting_position + self.index + time)
-            .is_multiple_of(self.positions)
+    /// The disc is aligned at every time congruent to the residue modulo the amount of positions.
+    const fn alignment(&self) -> (isize, isize) {
+        (-(self.starting_position + self.index), self.positions)
     }
 }
 
@@ -34,9 +34,9 @@ impl Machine {
         Ok(Self(parse_lines(Disc::parse, input)?))
     }
 
-    fn first_aligned_time(&self) -> Option<usize> {
-        let upper_bound = self.0.iter().map(|disc| disc.positions).product();
-        (0..upper_bound).find(|&time| self.0.iter().all(|disc| disc.is_aligned_at(time)))
+    fn first_aligned_time(&self) -> Option<isize> {
+        chinese_remainder(self.0.iter().map(Disc::alignment))
+            .map(|(time, _)| time)
     }
 
     fn add_disc(&mut self, disc: Disc) { self.0.push(disc) }
@@ -51,7 +51,7 @@ pub fn solve_part_1(input: &str) -> SolverResult {
 
 pub fn solve_part_2(input: &str) -> SolverResult {
     let mut machine = Machine::parse(input)?;
-    machine.add_disc(Disc { index: machine.total_discs() + 1, positions: 11, starting_position: 0 });
+    machine.add_disc(Disc { index: isize::try_from(machine.total_discs())? + 1, positions: 11, starting_position: 0 });
     
     let time = machine.first_aligned_time().ok_or(NoSolution)?;
     Ok(Box::new(time))
@@ -1,20 +1,18 @@
 use anyhow::bail;
-use aoc_lib::{parsing::{ParseError, TextParser, parse_lines}, math::Range};
+use aoc_lib::{parsing::{ParseError, TextParser, parse_lines}, math::{Inclusive, Range}};
 use yuki::errors::NoSolution;
 use crate::SolverResult;
 use itertools::Itertools;
-use nom::{character::complete::{char, u64}, sequence::separated_pair, combinator::map_res};
+use nom::{character::complete::{char, u32}, sequence::separated_pair, combinator::map_res};
 
-// Use u64 because range is represented using an exclusive upper bound
-// u32::MAX appears in the input and would require u32::MAX + 1 to represent this
-fn parse_range(input: &str) -> Result<Range<u64>, ParseError> {
+fn parse_range(input: &str) -> Result<Range<u32, Inclusive>, ParseError> {
     map_res(
-        separated_pair(u64, char('-'), u64),
+        separated_pair(u32, char('-'), u32),
         |(start, end)| Range::inclusive(start, end)
     ).run(input)
 }
 
-pub fn merge_ranges(ranges: impl IntoIterator<Item=Range<u64>>) -> impl Iterator<Item=Range<u64>> {
+pub fn merge_ranges(ranges: impl IntoIterator<Item=Range<u32, Inclusive>>) -> impl Iterator<Item=Range<u32, Inclusive>> {
     ranges.into_iter()
         .sorted_by_key(|range| range.start)
         .coalesce(Range::merge)
@@ -24,7 +22,7 @@ pub fn solve_part_1(input: &str) -> SolverResult {
     let ranges = parse_lines(parse_range, input)?;
     let first_valid_ip = match merge_ranges(ranges).next() {
         Some(Range { start, .. }) if start > 0 => 0,
-        Some(Range { end, .. }) => end,
+        Some(Range { end, .. }) => end.checked_add(1).ok_or(NoSolution)?,
         None => bail!(NoSolution)
     };
 
@@ -33,8 +31,9 @@ pub fn solve_part_1(input: &str) -> SolverResult {
 
 pub fn solve_part_2(input: &str) -> SolverResult {
     let ranges = parse_lines(parse_range, input)?;
+    // Count the ranges in a wider type, 0..=u32::MAX contains u32::MAX + 1 addresses
     let invalid_ips: u64 = merge_ranges(ranges)
-        .map(Range::interval)
+        .map(Range::interval_as::<u64>)
         .sum();
 
     // u32::MAX + 1 is the amount of values u32 can hold
@@ -1,6 +1,6 @@
-use std::{cmp::Ordering, iter::once, collections::VecDeque};
+use std::cmp::Ordering;
 
-use aoc_lib::{geometry::{Point2D, grid::{Grid, GridLike}, CardinalDirection, Dimensions}, parsing::{ParseError, TextParser, usize}, math::Bit, iteration::{queue::{Dedupable, FindState, SearchDepth}, ExtraIter}};
+use aoc_lib::{geometry::{Point2D, grid::{Grid, GridLike, GridPathfinding}, CardinalDirection, Dimensions}, parsing::{ParseError, TextParser, usize}, math::Bit, iteration::ExtraIter};
 use yuki::errors::NoSolution;
 use crate::SolverResult;
 use itertools::Itertools;
@@ -73,23 +73,9 @@ pub fn solve_part_1(input: &str) -> SolverResult {
     Ok(Box::new(viable_pairs))
 }
 
-// This code looks *very* generic
-// I think this could be fully abstracted to a generic grid search algorithm
 fn find_path(grid: &Grid<Bit>, from: Point2D<usize>, to: Point2D<usize>) -> Result<usize, NoSolution> {
-    once(SearchDepth::new(from)).collect::<VecDeque<_>>()
-        .filter_duplicates()
-        .recursive_find(|depth| {
-            if depth.state == to { return FindState::Result(depth.depth) }
-
-            let moves = depth.state
-                .neighbours::<isize, _>(CardinalDirection::all())
-                .filter_map(|position| {
-                    (!grid.get(position)?.is_solid())
-                        .then_some(depth.with(position))
-                }).collect_vec();
-
-            FindState::Branch(moves)
-        })
+    grid.shortest_paths(from, to, CardinalDirection::all(), |tile| !tile.is_solid())
+        .map(|paths| paths.cost)
         .ok_or(NoSolution)
 }
 
@@ -126,10 +112,9 @@ pub fn solve_part_2(input: &str) -> SolverResult {
 
     // Inefficient, but whatever
     let grid = grid.clone().map(|node| {
-        let neighbours = node
-            .position
-            .neighbours::<isize, _>(CardinalDirection::all())
-            .filter_map(|position| grid.get(position));
+        let neighbours = grid
+            .neighbours(node.position, CardinalDirection::all())
+            .map(|(_, neighbour)| neighbour);
 
         node.as_tile(neighbours)
     });
@@ -1,13 +1,9 @@
-use std::hash::{Hash, Hasher};
-
-use ahash::HashMap;
-use aoc_lib::parsing::{TextParser, parens, lines, TextParserResult, Parsable};
+use anyhow::Result;
+use aoc_lib::{datastructures::Tree, parsing::{TextParser, parens, lines, TextParserResult, Parsable}};
 use crate::SolverResult;
-use itertools::Itertools;
 use nom::{character::complete::{alpha1, space1, u32}, sequence::{terminated, preceded}, multi::separated_list1, bytes::complete::tag, combinator::opt, Parser};
 use thiserror::Error;
 
-#[derive(PartialEq, Eq, Hash)]
 struct Program<'a> {
     name: &'a str,
     weight: u32,
@@ -28,108 +24,37 @@ impl<'a> Parsable<'a> for Program<'a> {
 }
 
 #[derive(Debug, Error)]
-enum Error {
-    #[error("Node with name {0} does not exist")]
-    InvalidNode(String),
-    #[error("Multiple unbalanced nodes exist")]
-    MultipleUnbalancedNodes,
-    #[error("No unbalanced node exists")]
-    NoUnbalancedNode,
-    #[error("The tower has no nodes")]
-    NoNodes
-}
+#[error("No unbalanced node exists")]
+struct NoUnbalancedNodeError;
 
-fn find_root<'a, 'b>(tower: impl IntoIterator<Item=&'b Program<'a>>) -> Option<&'b Program<'a>> {
-    let tower: HashMap<&str, &Program> = tower.into_iter().flat_map(|node| {
-        node.children.iter().map(move |&child| (child, node))
-    }).collect();
+fn parse_tower(input: &str) -> Result<Tree<&str, u32>> {
+    let programs = lines(Program::parse).run(input)?;
+    let tower = Tree::from_adjacency(programs
+        .into_iter()
+        .map(|Program { name, weight, children }| (name, weight, children))
+    )?;
 
-    let mut node = *tower.values().next()?;
-    loop {
-        match tower.get(node.name) {
-            Some(parent) => node = parent,
-            None => break Some(node)
-        }
-    }
+    Ok(tower)
 }
 
 pub fn solve_part_1(input: &str) -> SolverResult {
-    let tower = lines(Program::parse).run(input)?;
-    let root = find_root(&tower).ok_or(Error::NoNodes)?;
-    Ok(Box::new(root.name.to_owned()))
-}
-
-#[derive(Debug, Clone, Copy)]
-struct BalancedNode {
-    weight: u32,
-    total_weight: u32
-}
-
-impl Eq for BalancedNode {}
-impl PartialEq for BalancedNode {
-    fn eq(&self, other: &Self) -> bool {
-        self.total_weight == other.total_weight
-    }
-}
-
-impl Hash for BalancedNode {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.total_weight.hash(state);
-    }
-}
-
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-enum Balanced {
-    Balanced(BalancedNode),
-    Unbalanced(u32)
-}
-
-// This seems way too verbose..
-// Also, stack recursion is dangerous as this could overflow the stack
-fn unbalanced_difference<'a: 'b, 'b>(tower: impl IntoIterator<Item=&'b Program<'a>>) -> Result<u32, Error> {
-    fn weight_difference<'a: 'b, 'b>(tower: &HashMap<&'a str, &'b Program<'a>>, root: &'b Program<'a>) -> Result<Balanced, Error> {
-        let child_weights = root.children.iter().map(|&name| {
-            let child = tower
-                .get(&name)
-                .ok_or_else(|| Error::InvalidNode(name.to_string()))?;
-            
-            weight_difference(tower, child)
-        }).collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .counts()
-            .into_iter()
-            .sorted_unstable_by_key(|(_, count)| *count)
-            .rev()
-            .collect_vec();
-
-        match child_weights[..] {
-            [] => Ok(Balanced::Balanced(BalancedNode { weight: root.weight, total_weight: root.weight })),
-            [(Balanced::Balanced(balanced), count)] => Ok(Balanced::Balanced(BalancedNode {
-                weight: root.weight,
-                total_weight: root.weight + balanced.total_weight * u32::try_from(count).unwrap()
-            })),
-            [(Balanced::Balanced(balanced), _), (Balanced::Balanced(unbalanced), 1)] => {
-                Ok(Balanced::Unbalanced(unbalanced.weight - (unbalanced.total_weight - balanced.total_weight)))
-            },
-            [_, (difference @ Balanced::Unbalanced(_), 1)] => Ok(difference),
-            _ => Err(Error::MultipleUnbalancedNodes)
-        }
-    }
-
-    let tower: HashMap<&str, &Program> = tower.into_iter()
-        .map(|node| (node.name, node))
-        .collect();
-
-    let root = find_root(tower.values().copied()).ok_or(Error::NoNodes)?;
-    match weight_difference(&tower, root)? {
-        Balanced::Balanced(_) => Err(Error::NoUnbalancedNode),
-        Balanced::Unbalanced(difference) => Ok(difference)
-    }
+    let tower = parse_tower(input)?;
+    Ok(Box::new(tower.root().to_string()))
 }
 
 pub fn solve_part_2(input: &str) -> SolverResult {
-    let tower: Vec<Program> = lines(Program::parse).run(input)?;
-    let difference = unbalanced_difference(&tower)?;
+    let tower = parse_tower(input)?;
+    let total_weights = tower.aggregate(|weight, children: &[&u32]| weight + children.iter().copied().sum::<u32>());
+
+    // The wrong weight belongs to the deepest program whose tower differs from those of its siblings
+    let mut unbalanced = None;
+    let mut program = tower.root();
+    while let Some((child, expected)) = tower.odd_one_out(program, |name, _| total_weights[name]) {
+        unbalanced = Some((child, expected));
+        program = child;
+    }
 
-    Ok(Box::new(difference))
+    let (program, expected) = unbalanced.ok_or(NoUnbalancedNodeError)?;
+    let weight = tower.get(program).copied().unwrap_or_default();
+    Ok(Box::new(weight + expected - total_weights[program]))
 }
\ No newline at end of file
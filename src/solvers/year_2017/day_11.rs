@@ -1,6 +1,4 @@
-use std::ops::Add;
-
-use aoc_lib::{geometry::{Point3D, HexDirection, Directional}, parsing::{TextParser, ParseError}};
+use aoc_lib::{geometry::{HexCoord, HexDirection}, parsing::{TextParser, ParseError}};
 use yuki::errors::NoInput;
 use crate::SolverResult;
 use nom::{multi::separated_list0, character::complete::char};
@@ -12,9 +10,8 @@ fn parse_steps(input: &str) -> Result<Vec<HexDirection>, ParseError> {
 pub fn solve_part_1(input: &str) -> SolverResult {
     let distance: i32 = parse_steps(input)?
         .into_iter()
-        .map(Directional::direction_vector)
-        .fold(Point3D::zero(), Add::add)
-        .hex_distance(Point3D::zero());
+        .fold(HexCoord::zero(), HexCoord::step)
+        .distance(HexCoord::zero());
 
     Ok(Box::new(distance))
 }
@@ -22,9 +19,9 @@ pub fn solve_part_1(input: &str) -> SolverResult {
 pub fn solve_part_2(input: &str) -> SolverResult {
     let furthest = parse_steps(input)?
         .into_iter()
-        .scan(Point3D::<i32>::zero(), |position, direction| {
-            *position += direction.direction_vector();
-            Some(position.hex_distance(Point3D::zero()))
+        .scan(HexCoord::<i32>::zero(), |position, direction| {
+            *position = position.step(direction);
+            Some(position.distance(HexCoord::zero()))
         })
         .max()
         .ok_or(NoInput)?;
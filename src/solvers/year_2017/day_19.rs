@@ -1,5 +1,5 @@
 use ahash::{HashSet, HashSetExt};
-use aoc_lib::{parsing::InvalidTokenError, geometry::{grid::{Grid, GridLike}, Point2D, CardinalDirection, Directional}};
+use aoc_lib::{parsing::InvalidTokenError, geometry::{grid::{Grid, GridLike}, Point2D, CardinalDirection, Direction2D, Directional}};
 use yuki::iterators::{ExtraIter, SingleError};
 use crate::SolverResult;
 use anyhow::{bail, Context, Result};
@@ -82,7 +82,7 @@ impl Map {
                         }).single();
 
                     match neighbours {
-                        Ok(neighbour) => packet.facing = packet.position.direction_to(neighbour).unwrap(),
+                        Ok(neighbour) => packet.facing = packet.position.direction_to(neighbour).and_then(Direction2D::cardinal).unwrap(),
                         Err(SingleError::More) => bail!("Corner had multiple valid paths"),
                         Err(SingleError::None) => break,
                     }
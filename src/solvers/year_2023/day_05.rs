@@ -1,9 +1,8 @@
-use aoc_lib::{math::{Range, InvalidRangeError}, between, parsing::{TextParserResult, ParseError, TextParser, skip_until}};
+use aoc_lib::{math::{Range, RangeSet, InvalidRangeError}, between, parsing::{TextParserResult, ParseError, TextParser, skip_until}};
 use crate::SolverResult;
 use itertools::Itertools;
 use nom::{character::complete::{char, u64, line_ending}, sequence::{preceded, delimited}, Parser, multi::separated_list1, bytes::complete::tag, combinator::map_res};
 use anyhow::{Context, Result};
-use rayon::iter::{ParallelBridge, ParallelIterator, IntoParallelIterator};
 
 struct Almanac(Vec<Map>);
 
@@ -15,23 +14,27 @@ impl Almanac {
         ).run(input)
     }
 
-    fn min_distance(&self, seeds: impl IntoParallelIterator<Item=u64>) -> Result<u64> {
-        seeds.into_par_iter().map(|seed| self.0
+    fn min_distance(&self, seeds: impl IntoIterator<Item=Range<u64>>) -> Result<u64> {
+        let locations = self.0
             .iter()
-            .fold(seed, |value, map| map.map(value))
-        )
-        .min()
-        .context("No seeds in input")
+            .fold(seeds.into_iter().collect(), |values, map| map.map(&values));
+
+        locations
+            .ranges()
+            .first()
+            .map(|range| range.start)
+            .context("No seeds in input")
     }
 }
 
 struct Map(Vec<MappingRange>);
 
 impl Map {
-    fn map(&self, n: u64) -> u64 {
-        self.0.iter()
-            .find_map(|range| range.map(n))
-            .unwrap_or(n)
+    fn map(&self, values: &RangeSet<u64>) -> RangeSet<u64> {
+        values.map_pieces(self.0
+            .iter()
+            .map(|range| (range.from, range.to.start))
+        )
     }
 
     fn parse(input: &str) -> TextParserResult<'_, Self> {
@@ -48,13 +51,6 @@ struct MappingRange {
 }
 
 impl MappingRange {
-    fn map(&self, n: u64) -> Option<u64> {
-        self.from.contains(&n).then(|| {
-            let offset = n - self.from.start;
-            self.to.start + offset
-        })
-    }
-
     fn parse(input: &str) -> TextParserResult<'_, Self> {
         map_res(
             (u64, between!(char(' '), u64), u64),
@@ -71,6 +67,8 @@ impl MappingRange {
 pub fn solve_part_1(input: &str) -> SolverResult {
     let (seeds, almanac) = Almanac::parse(input)?;
 
+    let seeds = seeds.into_iter().map(|seed| Range::new_unchecked(seed, seed + 1));
+
     let min_distance = almanac.min_distance(seeds)?;
     Ok(Box::new(min_distance))
 }
@@ -79,8 +77,7 @@ pub fn solve_part_2(input: &str) -> SolverResult {
     let (seeds, almanac) = Almanac::parse(input)?;
     let seeds = seeds.into_iter()
         .tuples()
-        .par_bridge()
-        .flat_map(|(start, length)| start..start + length);
+        .map(|(start, length)| Range::new_unchecked(start, start + length));
 
     let min_distance = almanac.min_distance(seeds)?;
     Ok(Box::new(min_distance))
@@ -1,32 +1,24 @@
-use aoc_lib::{parsing::TextParser, math::{Quadratic, Roots}};
+use aoc_lib::{parsing::TextParser, math::{Quadratic, Range}};
 use crate::SolverResult;
 use itertools::Itertools;
 use anyhow::Context;
-use nom::{bytes::complete::tag, character::complete::{line_ending, space1, u64}, multi::separated_list1, sequence::{pair, preceded, separated_pair}};
+use nom::{bytes::complete::tag, character::complete::{line_ending, space1, i64}, multi::separated_list1, sequence::{pair, preceded, separated_pair}};
 
 struct Race {
-    time: u64,
-    distance: u64
+    time: i64,
+    distance: i64
 }
 
 impl Race {
-    #[allow(
-        clippy::cast_precision_loss,
-        clippy::cast_possible_truncation,
-        clippy::cast_sign_loss
-    )]
-    fn winning_options(&self) -> u64 {
+    // Holding the button for x milliseconds travels x * (time - x) millimeters
+    fn winning_options(&self) -> i64 {
         let formula = Quadratic {
-            a: -1f64,
-            b: self.time as f64,
-            c: -(self.distance as f64)
+            a: -1,
+            b: self.time,
+            c: -self.distance
         };
 
-        match formula.roots() {
-            Roots::Pair(left, right) => (left.ceil() - right.floor()) as u64 - 1,
-            Roots::Single(_) => 1,
-            Roots::None => 0
-        }
+        formula.between_roots().map_or(0, Range::interval)
     }
 }
 
@@ -34,16 +26,16 @@ pub fn solve_part_1(input: &str) -> SolverResult {
     let (times, distances) = separated_pair(
         preceded(
             pair(tag("Time:"), space1),
-            separated_list1(space1, u64),
+            separated_list1(space1, i64),
         ),
         line_ending,
         preceded(
             pair(tag("Distance:"), space1),
-            separated_list1(space1, u64)
+            separated_list1(space1, i64)
         )
     ).run(input)?;
 
-    let margin_of_error: u64 = times
+    let margin_of_error: i64 = times
         .into_iter()
         .zip(distances)
         .map(|(time, distance)| Race { time, distance }.winning_options())
@@ -59,7 +51,7 @@ pub fn solve_part_2(input: &str) -> SolverResult {
             .chars()
             .filter(char::is_ascii_digit)
             .collect::<String>()
-            .parse::<u64>()
+            .parse::<i64>()
         )
         .collect_tuple()
         .context("Input did not have exactly 2 lines")?;
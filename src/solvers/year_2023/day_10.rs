@@ -37,8 +37,9 @@ impl Tile {
     }
 }
 
-fn construct_loop(map: &Grid<Tile>, mut position: Point2D<usize>) -> Result<Polygon<isize>> {
-    let mut seen: Polygon<isize> = Polygon::new();
+fn construct_loop(map: &Grid<Tile>, start: Point2D<usize>) -> Result<Polygon<isize>> {
+    let mut polygon: Polygon<isize> = Polygon::new();
+    let mut position = start;
     let mut facing = match map[position] {
         Tile::Ground => bail!("Starting position is not a pipe"),
         Tile::Pipe(entrance, _) => entrance.reverse()
@@ -51,9 +52,8 @@ fn construct_loop(map: &Grid<Tile>, mut position: Point2D<usize>) -> Result<Poly
         position = position.checked_add::<isize>(facing.direction_vector())
             .context("Moved off the grid")?;
 
-        if facing != prev_facing && !seen.push_point(prev_pos.try_map()?) {
-            return Ok(seen)
-        }
+        if facing != prev_facing { polygon.push_point(prev_pos.try_map()?); }
+        if position == start { return Ok(polygon) }
     }
 }
 
@@ -94,5 +94,5 @@ pub fn solve_part_2(input: &str) -> SolverResult {
     let (map, start) = parse_map(input)?;
     let polygon = construct_loop(&map, start)?;
 
-    Ok(Box::new(polygon.pick() - polygon.circumference()))
+    Ok(Box::new(polygon.interior_points()))
 }
\ No newline at end of file
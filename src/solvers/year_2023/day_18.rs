@@ -1,12 +1,12 @@
 use anyhow::bail;
-use aoc_lib::{geometry::{CardinalDirection, Point2D, Directional, Polygon}, parsing::{ParseError, Parsable, TextParser, parse_lines, usize, skip_until, parens}};
+use aoc_lib::{geometry::{CardinalDirection, Point2D, Polygon}, parsing::{ParseError, Parsable, TextParser, parse_lines, skip_until, parens}};
 use crate::SolverResult;
-use nom::{sequence::{separated_pair, terminated, preceded, pair}, character::complete::{char, anychar}, combinator::{rest, map_res}, Parser, bytes::complete::take};
+use nom::{sequence::{separated_pair, terminated, preceded, pair}, character::complete::{char, anychar, i64}, combinator::{rest, map_res}, Parser, bytes::complete::take};
 
 #[derive(Debug)]
 struct Instruction {
     direction: CardinalDirection,
-    amount: usize
+    amount: i64
 }
 
 impl Instruction {
@@ -14,7 +14,7 @@ impl Instruction {
         separated_pair(
             CardinalDirection::parse,
             char(' '),
-            terminated(usize, rest)
+            terminated(i64, rest)
         ).map(|(direction, amount)| Self { direction, amount }).run(input)
     }
 
@@ -27,7 +27,7 @@ impl Instruction {
             _ => bail!("Invalid direction hex digit: {char}")
         }));
 
-        let amount = map_res(take(5usize), |hex| usize::from_str_radix(hex, 16));
+        let amount = map_res(take(5usize), |hex| i64::from_str_radix(hex, 16));
         let hex = preceded(char('#'), pair(amount, direction))
             .map(|(amount, direction)| Self { direction, amount });
 
@@ -36,11 +36,10 @@ impl Instruction {
 }
 
 fn create_polygon(instructions: impl IntoIterator<Item=Instruction>) -> Polygon<i64> {
-    instructions.into_iter()
-        .scan(Point2D::zero(), |point, instruction| {
-            *point += instruction.direction.direction_vector() * i64::try_from(instruction.amount).unwrap();
-            Some(*point)
-        }).collect()
+    let steps = instructions.into_iter()
+        .map(|instruction| (instruction.direction, instruction.amount));
+
+    Polygon::from_steps(Point2D::zero(), steps)
 }
 
 pub fn solve_part_1(input: &str) -> SolverResult {
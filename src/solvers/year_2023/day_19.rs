@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use anyhow::{anyhow, bail, Context, Result};
-use aoc_lib::{parsing::{TextParserResult, curly_brackets, ParseError, lines, TextParser, Map2}, math::Range, range};
+use aoc_lib::{parsing::{TextParserResult, curly_brackets, ParseError, lines, TextParser, Map2}, geometry::Cuboid, range};
 use crate::SolverResult;
 use nom::{combinator::map_res, character::complete::{anychar, char, u16, alpha1, line_ending}, multi::{separated_list1, count}, sequence::{preceded, pair, separated_pair, terminated}, Parser};
 
@@ -22,8 +22,19 @@ impl Category {
             _ => bail!("Invalid category: {char}")
         })).parse(input)
     }
+
+    const fn axis(self) -> usize {
+        match self {
+            Self::ExtremelyCool => 0,
+            Self::Musical => 1,
+            Self::Aerodynamic => 2,
+            Self::Shiny => 3
+        }
+    }
 }
 
+type Area = Cuboid<u64, 4>;
+
 #[derive(Debug)]
 struct Part {
     x: u16,
@@ -118,17 +129,14 @@ impl<'a> Rule<'a> {
         }
     }
 
-    fn match_area(&self, area: Area) -> (Area, Option<Area>) {
+    fn match_area(&self, area: Area) -> (Option<Area>, Option<Area>) {
         match self.condition {
-            Condition::Always => (area, None),
-            Condition::Greater(dimension, partition_point) => {
-                let (remaining, matched) = area.partition(dimension, partition_point + 1);
-                (matched, Some(remaining))
+            Condition::Always => (Some(area), None),
+            Condition::Greater(category, value) => {
+                let (remaining, matched) = area.split(category.axis(), u64::from(value) + 1);
+                (matched, remaining)
             },
-            Condition::Smaller(dimension, partition_point) => {
-                let (matched, remaining) = area.partition(dimension, partition_point);
-                (matched, Some(remaining))
-            }
+            Condition::Smaller(category, value) => area.split(category.axis(), u64::from(value))
         }
     }
 }
@@ -167,15 +175,17 @@ impl<'a> Workflow<'a> {
 
         for rule in &self.0 {
             let (matched, remaining) = rule.match_area(area);
-            accepted += match rule.action {
-                Action::Accept => matched.area(),
-                Action::Reject => 0,
-                Action::RunWorkflow(name) => {
-                    workflows.get(name)
-                        .ok_or_else(|| anyhow!("No workflow with the name: '{name}' exists"))?
-                        .accepts(matched, workflows)?
-                }
-            };
+            if let Some(matched) = matched {
+                accepted += match rule.action {
+                    Action::Accept => matched.volume(),
+                    Action::Reject => 0,
+                    Action::RunWorkflow(name) => {
+                        workflows.get(name)
+                            .ok_or_else(|| anyhow!("No workflow with the name: '{name}' exists"))?
+                            .accepts(matched, workflows)?
+                    }
+                };
+            }
 
             if let Some(remaining) = remaining {
                 area = remaining;
@@ -208,53 +218,11 @@ pub fn solve_part_1(input: &str) -> SolverResult {
     Ok(Box::new(total_rating))
 }
 
-#[derive(Clone, Copy)]
-struct Area {
-    x: Range<u16>,
-    m: Range<u16>,
-    a: Range<u16>,
-    s: Range<u16>
-}
-
-impl Area {
-    fn get_dimension_mut(&mut self, category: Category) -> &mut Range<u16> {
-        match category {
-            Category::ExtremelyCool => &mut self.x,
-            Category::Musical => &mut self.m,
-            Category::Aerodynamic => &mut self.a,
-            Category::Shiny => &mut self.s
-        }
-    }
-
-    fn area(self) -> u64 {
-        let Self { x, m, a, s } = self;
-        [x, m, a, s].into_iter()
-            .map(|range| u64::from(range.interval()))
-            .product()
-    }
-
-    fn partition(mut self, dimension: Category, partition_point: u16) -> (Self, Self) {
-        let mut left = self;
-        let range = left.get_dimension_mut(dimension);
-        range.end = partition_point;
-
-        let range = self.get_dimension_mut(dimension);
-        range.start = partition_point;
-
-        (left, self)
-    }
-}
-
 pub fn solve_part_2(input: &str) -> SolverResult {
     let (workflows, _) = parse(input)?;
 
     let start = workflows.get("in").context("No workflow named 'in'")?;
-    let accepted = start.accepts(Area {
-        x: range!(1..=4000),
-        m: range!(1..=4000),
-        a: range!(1..=4000),
-        s: range!(1..=4000)
-    }, &workflows)?;
+    let accepted = start.accepts(Cuboid::new([range!(1..4001); 4]), &workflows)?;
 
     Ok(Box::new(accepted))
 }
\ No newline at end of file
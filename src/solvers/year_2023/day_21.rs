@@ -1,14 +1,15 @@
-use std::{collections::BTreeSet, iter::once};
+use std::{collections::{BTreeSet, VecDeque}, iter::once};
 
 use anyhow::{Context, Result, bail};
-use aoc_lib::{geometry::{grid::{Grid, GridLike}, Point2D, CardinalDirection}, functional::repeat_apply};
-use nom::Input;
+use ahash::{HashMap, HashMapExt};
+use aoc_lib::{geometry::{grid::{Grid, GridLike, TilingGridView}, Point2D, CardinalDirection}, functional::repeat_apply, math::extrapolate};
 use crate::SolverResult;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Tile {
     GardenPlot,
-    Rock
+    Rock,
+    Start
 }
 
 impl TryFrom<char> for Tile {
@@ -17,34 +18,33 @@ impl TryFrom<char> for Tile {
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
             '#' => Ok(Self::Rock),
-            '.' | 'S' => Ok(Self::GardenPlot),
+            '.' => Ok(Self::GardenPlot),
+            'S' => Ok(Self::Start),
             _ => bail!("Invalid tile: '{value}'")
         }
     }
 }
 
 fn parse_grid(input: &str) -> Result<(Point2D<usize>, Grid<Tile>)> {
-    let start = input.lines()
-        .enumerate()
-        .find_map(|(y, line)| Some(Point2D(line.position(|char| char == 'S')?, y)))
+    let grid: Grid<Tile> = match Grid::parse(input) {
+        Ok(grid) => grid,
+        Err(error) => bail!(error)
+    };
+
+    let start = grid.find(|&tile| tile == Tile::Start)
         .context("Input contains no starting position")?;
 
-    match Grid::parse(input) {
-        Ok(grid) => Ok((start, grid)),
-        Err(error) => bail!(error)
-    }
+    Ok((start, grid))
 }
 
 fn step(mut locations: BTreeSet<Point2D<usize>>, grid: &Grid<Tile>) -> BTreeSet<Point2D<usize>> {
     let mut new_locations: BTreeSet<Point2D<usize>> = BTreeSet::new();
 
     while let Some(location) = locations.pop_first() {
-        location
-            .neighbours::<isize, _>(CardinalDirection::all())
-            .filter_map(|neighbour| {
-                (grid.get(neighbour)? == &Tile::GardenPlot).then_some(neighbour)
-            }).
-            collect_into(&mut new_locations);
+        grid.neighbours(location, CardinalDirection::all())
+            .filter(|&(_, &tile)| tile != Tile::Rock)
+            .map(|(neighbour, _)| neighbour)
+            .collect_into(&mut new_locations);
     }
 
     new_locations
@@ -57,4 +57,43 @@ pub fn solve_part_1(input: &str) -> SolverResult {
     let final_steps = repeat_apply(64, initial_steps, |steps| step(steps, &grid));
 
     Ok(Box::new(final_steps.len()))
+}
+
+fn distances(grid: TilingGridView<'_, Tile>, start: Point2D<isize>, max_distance: usize) -> HashMap<Point2D<isize>, usize> {
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((location, distance)) = queue.pop_front() {
+        if distance > max_distance || distances.contains_key(&location) { continue }
+        distances.insert(location, distance);
+
+        grid.neighbours(location, CardinalDirection::all())
+            .filter(|&(_, &tile)| tile != Tile::Rock)
+            .map(|(neighbour, _)| (neighbour, distance + 1))
+            .collect_into(&mut queue);
+    }
+
+    distances
+}
+
+pub fn solve_part_2(input: &str) -> SolverResult {
+    const STEPS: usize = 26_501_365;
+
+    let (start, grid) = parse_grid(input)?;
+    let size = grid.area().dimensions().width();
+    if size != grid.area().dimensions().height() { bail!("Garden is not square") }
+
+    // The amount of reachable plots grows quadratically with every time the walk crosses another copy of the garden
+    let offset = STEPS % size;
+    let distances = distances(TilingGridView::new(&grid), start.map(usize::cast_signed), offset + 2 * size);
+    let reachable = |steps: usize| distances
+        .values()
+        .filter(|&&distance| distance <= steps && distance % 2 == steps % 2)
+        .count()
+        .cast_signed();
+
+    let samples = [0, 1, 2].map(|copies| reachable(offset + copies * size));
+    let plots = extrapolate(&samples, (STEPS / size).cast_signed());
+
+    Ok(Box::new(plots))
 }
\ No newline at end of file
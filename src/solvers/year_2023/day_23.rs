@@ -2,7 +2,7 @@ use std::{iter::once, cmp::max, collections::BTreeSet};
 
 use ahash::{HashSet, HashSetExt, HashMap, HashMapExt};
 use anyhow::{Result, bail, Context};
-use aoc_lib::geometry::{CardinalDirection, grid::{Grid, GridLike}, Point2D};
+use aoc_lib::geometry::{CardinalDirection, Direction2D, grid::{Grid, GridLike}, Point2D};
 use yuki::{iterators::{ExtraIter, SingleError}, tuples::fst};
 use crate::SolverResult;
 use itertools::Itertools;
@@ -78,7 +78,7 @@ impl Map {
         loop {
             let directions = match self.tiles.get(position) {
                 Some(Tile::Slope(direction)) => {
-                    if direction == &previous.direction_to(position).unwrap() {
+                    if previous.direction_to(position) == Some(Direction2D::Cardinal(*direction)) {
                         vec![*direction]
                     } else { vec![] }
                 },
@@ -115,7 +115,7 @@ impl Map {
                 .filter(|&neighbour| match self.tiles.get(neighbour) {
                     Some(Tile::Path) => true,
                     Some(Tile::Forest) | None => false,
-                    Some(Tile::Slope(direction)) => direction == &node.direction_to(neighbour).unwrap()
+                    Some(Tile::Slope(direction)) => node.direction_to(neighbour) == Some(Direction2D::Cardinal(*direction))
                 }).filter_map(|neighbour| {
                     self.follow_straight(node, neighbour)
                 }).collect_vec();
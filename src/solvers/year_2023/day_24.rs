@@ -1,35 +1,39 @@
 use std::fmt::Display;
 
-use aoc_lib::{geometry::{Point3D, Point2D}, parsing::{ParseError, Parsable, TextParser, parse_lines}, ignore};
+use anyhow::Context;
+use aoc_lib::{geometry::{Point3D, Point2D, Line}, math::solve_linear_system, parsing::{ParseError, Parsable, TextParser, parse_lines}, ignore};
 use crate::SolverResult;
 use itertools::Itertools;
 use nom::{sequence::separated_pair, Parser, character::complete::{char, space1}};
+use num::{BigInt, rational::Ratio};
 
 #[derive(Clone, Copy)]
 struct Hailstone {
-    position: Point3D<f64>,
-    velocity: Point3D<f64>
+    position: Point3D<i128>,
+    velocity: Point3D<i128>
 }
 
 impl Hailstone {
     fn parse(input: &str) -> Result<Self, ParseError> {
         separated_pair(
-            Point3D::<f64>::parse,
+            Point3D::<i128>::parse,
             ignore!(space1, char('@'), space1),
-            Point3D::<f64>::parse
+            Point3D::<i128>::parse
         ).map(|(position, velocity)| Self {
             position, velocity
         }).run(input)
     }
 
-    fn collides_2d(self, other: Self) -> Option<Point2D<f64>> {
-        let Self { position: Point3D(x1, y1, _z1), velocity: Point3D(vx1, vy1, _vz1) } = self;
-        let Self { position: Point3D(x2, y2, _z2), velocity: Point3D(vx2, vy2, _vz2) } = other;
-
-        let x = ((-vy2 / vx2).mul_add(x2, y2) - (-vy1 / vx1).mul_add(x1, y1)) / (vy1 / vx1 - vy2 / vx2);
-        let y = (vy1 / vx1).mul_add(x, (-vy1 / vx1).mul_add(x1, y1));
+    const fn trajectory_2d(self) -> Line<Point2D<i128>> {
+        let Point3D(x, y, _) = self.position;
+        let Point3D(vx, vy, _) = self.velocity;
+        Line { origin: Point2D(x, y), direction: Point2D(vx, vy) }
+    }
 
-        ((x - x1) / vx1 >= 0.0 && (x - x2) / vx2 >= 0.0).then_some(Point2D(x, y))
+    fn collides_2d(self, other: Self) -> Option<Point2D<Ratio<i128>>> {
+        self.trajectory_2d()
+            .intersect_rays(&other.trajectory_2d())
+            .map(|intersection| intersection.point)
     }
 }
 
@@ -40,15 +44,57 @@ impl Display for Hailstone {
 }
 
 pub fn solve_part_1(input: &str) -> SolverResult {
-    const MIN: f64 = 200_000_000_000_000.0;
-    const MAX: f64 = 400_000_000_000_000.0;
+    const MIN: i128 = 200_000_000_000_000;
+    const MAX: i128 = 400_000_000_000_000;
 
+    let bounds = Ratio::from_integer(MIN)..=Ratio::from_integer(MAX);
     let collisions = parse_lines(Hailstone::parse, input)?
         .into_iter()
         .tuple_combinations()
         .filter_map(|(a, b)| a.collides_2d(b))
-        .filter(|&Point2D(x, y)| x >= MIN && y >= MIN && x <= MAX && y <= MAX)
+        .filter(|Point2D(x, y)| bounds.contains(x) && bounds.contains(y))
         .count();
 
     Ok(Box::new(collisions))
+}
+
+// The rock at position `p` with velocity `v` hits every hailstone `i`,
+// this means `(p - p_i) x (v - v_i) = 0` for every hailstone.
+// Subtracting this equation for two hailstones `i` and `j` cancels out the non-linear `p x v` term:
+// `p x (v_i - v_j) + (p_i - p_j) x v = p_i x v_i - p_j x v_j`.
+// Two pairs of hailstones produce the six equations needed to solve for `p` and `v`.
+fn rock_equations(a: Hailstone, b: Hailstone, c: Hailstone) -> Vec<[BigInt; 7]> {
+    let pair_equations = |first: Hailstone, second: Hailstone| {
+        let Point3D(ax, ay, az) = first.velocity - second.velocity;
+        let Point3D(bx, by, bz) = first.position - second.position;
+        let Point3D(cx, cy, cz) = first.position.cross(first.velocity) - second.position.cross(second.velocity);
+
+        [
+            [0, az, -ay, 0, -bz, by, cx],
+            [-az, 0, ax, bz, 0, -bx, cy],
+            [ay, -ax, 0, -by, bx, 0, cz]
+        ].map(|equation| equation.map(BigInt::from))
+    };
+
+    pair_equations(a, b)
+        .into_iter()
+        .chain(pair_equations(a, c))
+        .collect()
+}
+
+pub fn solve_part_2(input: &str) -> SolverResult {
+    let hailstones = parse_lines(Hailstone::parse, input)?;
+
+    let solution = hailstones
+        .into_iter()
+        .tuple_combinations()
+        .find_map(|(a, b, c)| solve_linear_system(rock_equations(a, b, c)))
+        .context("No rock trajectory hits every hailstone")?;
+
+    let position: Ratio<BigInt> = solution
+        .into_iter()
+        .take(3)
+        .sum();
+
+    Ok(Box::new(position))
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use aoc_lib::math::Range;
+use aoc_lib::math::{Inclusive, Range};
 use itertools::Itertools;
 use nom::{Parser, character::complete::{char, line_ending, u64}, multi::count, sequence::separated_pair};
 use yuki::parsing::{Parsable, ParsingResult, combinators::lines, parse};
@@ -7,11 +7,11 @@ use crate::SolverResult;
 
 #[derive(Debug)]
 struct Inventory {
-    fresh_ingredients: Vec<Range<u64>>,
+    fresh_ingredients: Vec<Range<u64, Inclusive>>,
     available_ingredients: Vec<u64>
 }
 
-fn parse_range(input: &'_ str) -> ParsingResult<'_, Range<u64>> {
+fn parse_range(input: &'_ str) -> ParsingResult<'_, Range<u64, Inclusive>> {
     separated_pair(u64, char('-'), u64)
         .map_res(|(lower, upper)| Range::inclusive(lower, upper))
         .parse(input)